@@ -0,0 +1,100 @@
+//! Compares the inline `HDPath` against the previous `Vec<HDPathComponent>`
+//! backed representation, which is reproduced here as the baseline.
+
+use std::{hint::black_box, str::FromStr};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use hdpath::prelude::*;
+
+/// The previous representation of `HDPath`.
+struct VecHDPath(Vec<HDPathComponent>);
+
+/// The purpose and coin type components, constants in the previous
+/// representation, so created once outside of the measured closures.
+fn purpose_and_coin_type() -> [HDPathComponent; 2] {
+    [
+        HDPathComponent::from_str("44H").unwrap(),
+        HDPathComponent::from_str("1022H").unwrap(),
+    ]
+}
+
+impl VecHDPath {
+    fn cap26(
+        [purpose, coin_type]: [HDPathComponent; 2],
+        network_id: NetworkID,
+        key_kind: CAP26KeyKind,
+        index: Hardened,
+    ) -> Self {
+        Self(vec![
+            purpose,
+            coin_type,
+            HDPathComponent::from(network_id),
+            HDPathComponent::from(CAP26EntityKind::Account),
+            HDPathComponent::from(key_kind),
+            HDPathComponent::from(index),
+        ])
+    }
+
+    fn hardened_chain(&self) -> Vec<u32> {
+        self.0.iter().map(|c| c.map_to_global_key_space()).collect()
+    }
+}
+
+fn to_hd_path(c: &mut Criterion) {
+    let prefix = purpose_and_coin_type();
+    let mut group = c.benchmark_group("cap26_account_path_to_hd_path");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            VecHDPath::cap26(
+                prefix,
+                black_box(NetworkID::Mainnet),
+                black_box(CAP26KeyKind::TransactionSigning),
+                black_box(Hardened::sample()),
+            )
+        })
+    });
+    group.bench_function("inline", |b| {
+        b.iter(|| {
+            CAP26AccountPath::new(
+                black_box(NetworkID::Mainnet),
+                black_box(CAP26KeyKind::TransactionSigning),
+                black_box(Hardened::sample()),
+            )
+            .to_hd_path()
+        })
+    });
+    group.finish();
+}
+
+fn hardened_chain(c: &mut Criterion) {
+    let vec_path = VecHDPath::cap26(
+        purpose_and_coin_type(),
+        NetworkID::Mainnet,
+        CAP26KeyKind::TransactionSigning,
+        Hardened::sample(),
+    );
+    let path = CAP26AccountPath::sample().to_hd_path();
+
+    let mut group = c.benchmark_group("hardened_chain");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            black_box(&vec_path)
+                .hardened_chain()
+                .into_iter()
+                .fold(0u32, u32::wrapping_add)
+        })
+    });
+    group.bench_function("inline", |b| {
+        b.iter(|| {
+            black_box(&path)
+                .global_indices()
+                .iter()
+                .copied()
+                .fold(0u32, u32::wrapping_add)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, to_hd_path, hardened_chain);
+criterion_main!(benches);
@@ -0,0 +1,138 @@
+//! Compares the packed `HDPathComponent` against the previous nested enum
+//! representation, which is reproduced here as the baseline.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use hdpath::prelude::*;
+
+/// The previous representation of `HDPathComponent`.
+#[derive(Clone, Copy)]
+enum NestedHDPathComponent {
+    Unsecurified(Unsecurified),
+    Securified(SecurifiedU30),
+}
+
+impl NestedHDPathComponent {
+    fn from_global_key_space(value: u32) -> Self {
+        SecurifiedU30::from_global_key_space(value)
+            .map(Self::Securified)
+            .or(Unsecurified::from_global_key_space(value).map(Self::Unsecurified))
+            .unwrap()
+    }
+
+    fn map_to_global_key_space(&self) -> u32 {
+        match self {
+            Self::Unsecurified(u) => u.map_to_global_key_space(),
+            Self::Securified(s) => s.map_to_global_key_space(),
+        }
+    }
+
+    fn key_space(&self) -> KeySpace {
+        match self {
+            Self::Unsecurified(u) => u.key_space(),
+            Self::Securified(s) => s.key_space(),
+        }
+    }
+}
+
+/// Globals spread evenly over all key spaces.
+fn globals() -> Vec<u32> {
+    (0..1024u32).map(|i| i.wrapping_mul(4_194_301)).collect()
+}
+
+fn map_to_global_key_space(c: &mut Criterion) {
+    assert_eq!(std::mem::size_of::<HDPathComponent>(), 4);
+    assert!(std::mem::size_of::<NestedHDPathComponent>() > 4);
+
+    let nested = globals()
+        .into_iter()
+        .map(NestedHDPathComponent::from_global_key_space)
+        .collect::<Vec<_>>();
+    let packed = globals()
+        .into_iter()
+        .map(|g| HDPathComponent::from_global_key_space(g).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("map_to_global_key_space");
+    group.bench_function("nested", |b| {
+        b.iter(|| {
+            black_box(&nested)
+                .iter()
+                .map(|c| c.map_to_global_key_space())
+                .fold(0u32, u32::wrapping_add)
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            black_box(&packed)
+                .iter()
+                .map(|c| c.map_to_global_key_space())
+                .fold(0u32, u32::wrapping_add)
+        })
+    });
+    group.finish();
+}
+
+fn key_space(c: &mut Criterion) {
+    let nested = globals()
+        .into_iter()
+        .map(NestedHDPathComponent::from_global_key_space)
+        .collect::<Vec<_>>();
+    let packed = globals()
+        .into_iter()
+        .map(|g| HDPathComponent::from_global_key_space(g).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("key_space");
+    group.bench_function("nested", |b| {
+        b.iter(|| {
+            black_box(&nested)
+                .iter()
+                .filter(|c| c.key_space().is_securified())
+                .count()
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            black_box(&packed)
+                .iter()
+                .filter(|c| c.key_space().is_securified())
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn from_global_key_space(c: &mut Criterion) {
+    let globals = globals();
+
+    let mut group = c.benchmark_group("from_global_key_space");
+    group.bench_function("nested", |b| {
+        b.iter(|| {
+            black_box(&globals)
+                .iter()
+                .map(|g| NestedHDPathComponent::from_global_key_space(*g))
+                .filter(|c| c.key_space().is_securified())
+                .count()
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            black_box(&globals)
+                .iter()
+                .map(|g| HDPathComponent::from_global_key_space(*g).unwrap())
+                .filter(|c| c.key_space().is_securified())
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    map_to_global_key_space,
+    key_space,
+    from_global_key_space
+);
+criterion_main!(benches);
@@ -0,0 +1,155 @@
+use crate::prelude::*;
+
+entity_address!(
+    /// The virtual address of an account controlled by a single public key,
+    /// e.g. `account_rdx12...` for the Ed25519 key at a `CAP26AccountPath`,
+    /// or `account_rdx16...` for the Secp256k1 key of an Olympia account.
+    AccountAddress,
+    "account",
+    ed25519: 0x51,
+    secp256k1: 0xD1
+);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = AccountAddress;
+
+    fn ed25519(hex: &str) -> Ed25519PublicKey {
+        Ed25519PublicKey::from_bytes(&hex::decode(hex).unwrap().try_into().unwrap()).unwrap()
+    }
+
+    fn secp256k1() -> Secp256k1PublicKey {
+        Secp256k1PublicKey::from_bytes(
+            &hex::decode("03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ed25519_mainnet() {
+        let key = ed25519("c83031436fffc3da2059493c47e55c3153b8c9f1345ae005ba5b1da2d6850785");
+        assert_eq!(
+            Sut::new(key, NetworkID::Mainnet).to_string(),
+            "account_rdx12939q7jvjc0q9vvqtc87uv6eu334yagtak7k9udekafy66gpvu222n"
+        );
+    }
+
+    #[test]
+    fn ed25519_stokenet() {
+        let key = ed25519("2063435abcb8c20b55bced3d6206fe00fbaedbe1e2c88555827486985c410c9e");
+        assert_eq!(
+            Sut::new(key, NetworkID::Stokenet).to_string(),
+            "account_tdx_2_129y4vzvvdwjv84hfvj38ss226avmknqjydhu9xuxjuadtwhfst7yc5"
+        );
+    }
+
+    #[test]
+    fn secp256k1_mainnet() {
+        assert_eq!(
+            PublicKey::from(secp256k1())
+                .to_account_address(NetworkID::Mainnet)
+                .to_string(),
+            "account_rdx168cucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2k4jrhtk"
+        );
+    }
+
+    #[test]
+    fn node_id_and_network_id() {
+        let sut = Sut::new(secp256k1(), NetworkID::Stokenet);
+        assert_eq!(sut.node_id()[0], 0xD1);
+        assert_eq!(
+            sut.node_id()[1..],
+            blake2b_256_hash(secp256k1().to_bytes())[3..]
+        );
+        assert_eq!(sut.network_id(), NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        for s in [
+            "account_rdx12939q7jvjc0q9vvqtc87uv6eu334yagtak7k9udekafy66gpvu222n",
+            "account_tdx_2_129y4vzvvdwjv84hfvj38ss226avmknqjydhu9xuxjuadtwhfst7yc5",
+            "account_rdx168cucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2k4jrhtk",
+        ] {
+            assert_eq!(Sut::from_str(s).unwrap().to_string(), s);
+        }
+        assert_eq!(
+            Sut::from_str("account_tdx_2_129y4vzvvdwjv84hfvj38ss226avmknqjydhu9xuxjuadtwhfst7yc5")
+                .unwrap()
+                .network_id(),
+            NetworkID::Stokenet
+        );
+    }
+
+    #[test]
+    fn from_str_identity_is_err() {
+        assert_eq!(
+            Sut::from_str("identity_rdx12g5v7qspjdxwfhvpa2lzjmxgtr2ywgeuec5eftaxn924d7qxcfw8l8"),
+            Err(CommonError::InvalidAddressHRP)
+        );
+    }
+
+    #[test]
+    fn from_str_bad_checksum_is_err() {
+        assert_eq!(
+            Sut::from_str("account_rdx12939q7jvjc0q9vvqtc87uv6eu334yagtak7k9udekafy66gpvu222q"),
+            Err(CommonError::InvalidBech32m)
+        );
+    }
+
+    #[test]
+    fn from_str_bech32_is_err() {
+        let hrp = bech32::Hrp::parse("account_rdx").unwrap();
+        let s = bech32::encode::<bech32::Bech32>(hrp, &Sut::sample_node_id()).unwrap();
+        assert_eq!(Sut::from_str(&s), Err(CommonError::InvalidBech32m));
+    }
+
+    #[test]
+    fn from_str_unknown_network_is_err() {
+        let hrp = bech32::Hrp::parse("account_tdx_99_").unwrap();
+        let s = bech32::encode::<bech32::Bech32m>(hrp, &Sut::sample_node_id()).unwrap();
+        assert_eq!(Sut::from_str(&s), Err(CommonError::InvalidNetworkID));
+    }
+
+    #[test]
+    fn from_str_wrong_entity_type_is_err() {
+        let mut node_id = Sut::sample_node_id();
+        node_id[0] = 0xC1;
+        let hrp = bech32::Hrp::parse("account_rdx").unwrap();
+        let s = bech32::encode::<bech32::Bech32m>(hrp, &node_id).unwrap();
+        assert_eq!(
+            Sut::from_str(&s),
+            Err(CommonError::InvalidAddressEntityType)
+        );
+    }
+
+    #[test]
+    fn from_str_wrong_length_is_err() {
+        let hrp = bech32::Hrp::parse("account_rdx").unwrap();
+        let s = bech32::encode::<bech32::Bech32m>(hrp, &[0x51; 29]).unwrap();
+        assert_eq!(Sut::from_str(&s), Err(CommonError::InvalidAddressLength));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::new(secp256k1(), NetworkID::Mainnet);
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!("account_rdx168cucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2k4jrhtk"),
+        );
+        assert_json_roundtrip(&sut);
+    }
+
+    impl Sut {
+        fn sample_node_id() -> [u8; 30] {
+            Self::new(secp256k1(), NetworkID::Mainnet).node_id()
+        }
+    }
+}
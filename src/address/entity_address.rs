@@ -0,0 +1,121 @@
+/// Declares a Radix virtual entity address type, the Bech32m encoding of a
+/// 30 bytes node id, which is the entity type byte, dictated by the curve of
+/// the public key, followed by the last 29 bytes of the Blake2b-256 hash of
+/// the public key, with the HRP `{hrp_prefix}_{network hrp suffix}`.
+macro_rules! entity_address {
+    (
+        $(
+            #[doc = $expr: expr]
+        )*
+        $address_type: ident,
+        $hrp_prefix: literal,
+        ed25519: $ed25519_entity_type: literal,
+        secp256k1: $secp256k1_entity_type: literal
+    ) => {
+        $(
+            #[doc = $expr]
+        )*
+        #[derive(
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            Display,
+            MoreDebug,
+            SerializeDisplay,
+            DeserializeFromStr,
+        )]
+        #[display("{}", self.to_bech32m_string())]
+        #[debug("{}", self.to_bech32m_string())]
+        pub struct $address_type {
+            network_id: NetworkID,
+            node_id: [u8; 30],
+        }
+
+        impl $address_type {
+            const HRP_PREFIX: &'static str = $hrp_prefix;
+            const ED25519_ENTITY_TYPE: u8 = $ed25519_entity_type;
+            const SECP256K1_ENTITY_TYPE: u8 = $secp256k1_entity_type;
+
+            /// The virtual address of the entity controlled by `public_key`
+            /// on `network_id`.
+            pub fn new(public_key: impl Into<PublicKey>, network_id: NetworkID) -> Self {
+                let public_key = public_key.into();
+                let entity_type = match public_key {
+                    PublicKey::Ed25519(_) => Self::ED25519_ENTITY_TYPE,
+                    PublicKey::Secp256k1(_) => Self::SECP256K1_ENTITY_TYPE,
+                };
+                let hash = blake2b_256_hash(public_key.to_bytes());
+                let mut node_id = [0u8; 30];
+                node_id[0] = entity_type;
+                node_id[1..].copy_from_slice(&hash[3..]);
+                Self {
+                    network_id,
+                    node_id,
+                }
+            }
+
+            pub fn network_id(&self) -> NetworkID {
+                self.network_id
+            }
+
+            /// The entity type byte followed by the public key hash.
+            pub fn node_id(&self) -> [u8; 30] {
+                self.node_id
+            }
+
+            fn hrp(network_id: NetworkID) -> String {
+                format!("{}_{}", Self::HRP_PREFIX, network_id.hrp_suffix())
+            }
+
+            pub fn to_bech32m_string(&self) -> String {
+                let hrp = bech32::Hrp::parse(&Self::hrp(self.network_id))
+                    .expect("HRP of ASCII alphanumerics and `_`");
+                bech32::encode::<bech32::Bech32m>(hrp, &self.node_id)
+                    .expect("Address is shorter than the max Bech32m length")
+            }
+
+            /// Parses a Bech32m address, fails if it is not a virtual
+            /// address of this entity kind or the network is unknown.
+            pub fn from_bech32m_string(s: impl AsRef<str>) -> Result<Self> {
+                let checked =
+                    bech32::primitives::decode::CheckedHrpstring::new::<bech32::Bech32m>(
+                        s.as_ref(),
+                    )
+                    .map_err(|_| CommonError::InvalidBech32m)?;
+                let hrp = checked.hrp().to_lowercase();
+                let network_id = hrp
+                    .strip_prefix(Self::HRP_PREFIX)
+                    .and_then(|suffix| suffix.strip_prefix('_'))
+                    .ok_or(CommonError::InvalidAddressHRP)
+                    .and_then(NetworkID::from_hrp_suffix)?;
+                let node_id: [u8; 30] = checked
+                    .byte_iter()
+                    .collect::<Vec<u8>>()
+                    .try_into()
+                    .map_err(|_| CommonError::InvalidAddressLength)?;
+                if ![Self::ED25519_ENTITY_TYPE, Self::SECP256K1_ENTITY_TYPE]
+                    .contains(&node_id[0])
+                {
+                    return Err(CommonError::InvalidAddressEntityType);
+                }
+                Ok(Self {
+                    network_id,
+                    node_id,
+                })
+            }
+        }
+
+        impl FromStr for $address_type {
+            type Err = CommonError;
+            fn from_str(s: &str) -> Result<Self> {
+                Self::from_bech32m_string(s)
+            }
+        }
+    };
+}
+
+pub(crate) use entity_address;
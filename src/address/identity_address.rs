@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+entity_address!(
+    /// The virtual address of an identity (Persona) controlled by a single
+    /// public key, e.g. `identity_rdx12...` for the Ed25519 key at a
+    /// `CAP26IdentityPath`.
+    IdentityAddress,
+    "identity",
+    ed25519: 0x52,
+    secp256k1: 0xD2
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = IdentityAddress;
+
+    fn ed25519(hex: &str) -> Ed25519PublicKey {
+        Ed25519PublicKey::from_bytes(&hex::decode(hex).unwrap().try_into().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn ed25519_mainnet() {
+        let key = ed25519("701531ff4922259f213f2ff8443f4eab12b2079641d0f8cf5554bfae185b53e6");
+        assert_eq!(
+            Sut::new(key, NetworkID::Mainnet).to_string(),
+            "identity_rdx12g5v7qspjdxwfhvpa2lzjmxgtr2ywgeuec5eftaxn924d7qxcfw8l8"
+        );
+    }
+
+    #[test]
+    fn ed25519_stokenet() {
+        let key = ed25519("28a80dd67c64cf00862066dbd533a1c4975b78d4875d2208d576193aa67c0967");
+        assert_eq!(
+            PublicKey::from(key)
+                .to_identity_address(NetworkID::Stokenet)
+                .to_string(),
+            "identity_tdx_2_12fvk5f3fjzp33h82uppeuqwtcqmxp4zx4ts0e3nw56v55nnw69xenv"
+        );
+    }
+
+    #[test]
+    fn secp256k1_stokenet() {
+        let key = Secp256k1PublicKey::from_bytes(
+            &hex::decode("03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            Sut::new(key, NetworkID::Stokenet).to_string(),
+            "identity_tdx_2_16tcucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2khjfq2r"
+        );
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        let s = "identity_tdx_2_12fvk5f3fjzp33h82uppeuqwtcqmxp4zx4ts0e3nw56v55nnw69xenv";
+        let sut = Sut::from_str(s).unwrap();
+        assert_eq!(sut.to_string(), s);
+        assert_eq!(sut.network_id(), NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn from_str_account_is_err() {
+        assert_eq!(
+            Sut::from_str("account_rdx12939q7jvjc0q9vvqtc87uv6eu334yagtak7k9udekafy66gpvu222n"),
+            Err(CommonError::InvalidAddressHRP)
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::prelude::*;
+
+/// The address of an Olympia account, the Bech32 (not Bech32m) encoding of
+/// the version byte `0x04` followed by the compressed Secp256k1 public key
+/// with the HRP `rdx`, e.g. `rdx1qsp...`. Only Mainnet accounts were
+/// migrated to Babylon, see `to_babylon_account_address`.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Display, MoreDebug, SerializeDisplay, DeserializeFromStr,
+)]
+#[display("{}", self.to_bech32_string())]
+#[debug("{}", self.to_bech32_string())]
+pub struct LegacyOlympiaAccountAddress {
+    public_key: Secp256k1PublicKey,
+}
+
+impl LegacyOlympiaAccountAddress {
+    const HRP: &'static str = "rdx";
+    const VERSION_BYTE: u8 = 0x04;
+
+    pub fn new(public_key: Secp256k1PublicKey) -> Self {
+        Self { public_key }
+    }
+
+    pub fn public_key(&self) -> Secp256k1PublicKey {
+        self.public_key
+    }
+
+    /// The Babylon account the Olympia account is migrated to, the virtual
+    /// account of the same public key on Mainnet.
+    pub fn to_babylon_account_address(&self) -> AccountAddress {
+        AccountAddress::new(self.public_key, NetworkID::Mainnet)
+    }
+
+    pub fn to_bech32_string(&self) -> String {
+        let hrp = bech32::Hrp::parse(Self::HRP).expect("Valid HRP");
+        let data = [&[Self::VERSION_BYTE][..], &self.public_key.to_bytes()].concat();
+        bech32::encode::<bech32::Bech32>(hrp, &data)
+            .expect("Address is shorter than the max Bech32 length")
+    }
+
+    /// Parses a Bech32 Olympia Mainnet account address.
+    pub fn from_bech32_string(s: impl AsRef<str>) -> Result<Self> {
+        let checked =
+            bech32::primitives::decode::CheckedHrpstring::new::<bech32::Bech32>(s.as_ref())
+                .map_err(|_| CommonError::InvalidBech32)?;
+        if checked.hrp().to_lowercase() != Self::HRP {
+            return Err(CommonError::InvalidAddressHRP);
+        }
+        let data: [u8; 34] = checked
+            .byte_iter()
+            .collect::<Vec<u8>>()
+            .try_into()
+            .map_err(|_| CommonError::InvalidAddressLength)?;
+        if data[0] != Self::VERSION_BYTE {
+            return Err(CommonError::InvalidOlympiaAddressVersionByte);
+        }
+        Secp256k1PublicKey::from_bytes(data[1..].try_into().unwrap()).map(Self::new)
+    }
+}
+
+impl From<Secp256k1PublicKey> for LegacyOlympiaAccountAddress {
+    fn from(public_key: Secp256k1PublicKey) -> Self {
+        Self::new(public_key)
+    }
+}
+
+impl FromStr for LegacyOlympiaAccountAddress {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bech32_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = LegacyOlympiaAccountAddress;
+
+    const ADDRESS: &str = "rdx1qspx7zxmnrh36q33av24srdfzg7m3cj65968erpjuh7ja3rm3kmn6hq4j9842";
+
+    fn public_key() -> Secp256k1PublicKey {
+        Secp256k1PublicKey::from_bytes(
+            &hex::decode("026f08db98ef1d0231eb15580da9123db8e25aa1747c8c32e5fd2ec47b8db73d5c")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn from_public_key() {
+        assert_eq!(Sut::new(public_key()).to_string(), ADDRESS);
+    }
+
+    #[test]
+    fn from_str() {
+        let sut = Sut::from_str(ADDRESS).unwrap();
+        assert_eq!(sut.public_key(), public_key());
+        assert_eq!(sut, Sut::from(public_key()));
+    }
+
+    #[test]
+    fn to_babylon_account_address() {
+        assert_eq!(
+            Sut::from_str(ADDRESS)
+                .unwrap()
+                .to_babylon_account_address()
+                .to_string(),
+            "account_rdx168e8u653alt59xm8ple6khu6cgce9cfx9mlza6wxf7qs3wwdh0pwrf"
+        );
+    }
+
+    #[test]
+    fn from_str_babylon_address_is_err() {
+        assert_eq!(
+            Sut::from_str("account_rdx168e8u653alt59xm8ple6khu6cgce9cfx9mlza6wxf7qs3wwdh0pwrf"),
+            Err(CommonError::InvalidBech32)
+        );
+    }
+
+    #[test]
+    fn from_str_bad_checksum_is_err() {
+        assert_eq!(
+            Sut::from_str("rdx1qspx7zxmnrh36q33av24srdfzg7m3cj65968erpjuh7ja3rm3kmn6hq4j9843"),
+            Err(CommonError::InvalidBech32)
+        );
+    }
+
+    fn encode(hrp: &str, data: &[u8]) -> String {
+        bech32::encode::<bech32::Bech32>(bech32::Hrp::parse(hrp).unwrap(), data).unwrap()
+    }
+
+    #[test]
+    fn from_str_wrong_hrp_is_err() {
+        let data = [&[0x04][..], &public_key().to_bytes()].concat();
+        assert_eq!(
+            Sut::from_str(&encode("tdx", &data)),
+            Err(CommonError::InvalidAddressHRP)
+        );
+    }
+
+    #[test]
+    fn from_str_wrong_version_byte_is_err() {
+        let data = [&[0x03][..], &public_key().to_bytes()].concat();
+        assert_eq!(
+            Sut::from_str(&encode("rdx", &data)),
+            Err(CommonError::InvalidOlympiaAddressVersionByte)
+        );
+    }
+
+    #[test]
+    fn from_str_wrong_length_is_err() {
+        assert_eq!(
+            Sut::from_str(&encode("rdx", &[0x04; 33])),
+            Err(CommonError::InvalidAddressLength)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_public_key_is_err() {
+        let data = [&[0x04][..], &[0x05; 33][..]].concat();
+        assert_eq!(
+            Sut::from_str(&encode("rdx", &data)),
+            Err(CommonError::InvalidSecp256k1PublicKey)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::new(public_key());
+        assert_json_value_eq_after_roundtrip(&sut, json!(ADDRESS));
+        assert_json_roundtrip(&sut);
+    }
+}
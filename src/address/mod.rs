@@ -0,0 +1,9 @@
+mod account_address;
+mod entity_address;
+mod identity_address;
+mod legacy_olympia_account_address;
+
+pub use account_address::*;
+pub(crate) use entity_address::*;
+pub use identity_address::*;
+pub use legacy_olympia_account_address::*;
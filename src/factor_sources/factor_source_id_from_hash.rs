@@ -0,0 +1,200 @@
+use crate::prelude::*;
+
+use bip39::Mnemonic;
+
+/// The ID of a factor source, its `FactorSourceKind` and the Blake2b-256
+/// hash of the Ed25519 public key at `CAP26GetIDPath`, formatted as
+/// `kind:hex`, e.g. `device:f1a93d...`.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    MoreDebug,
+    SerializeDisplay,
+    DeserializeFromStr,
+)]
+#[display("{}:{}", kind, hex::encode(body))]
+#[debug("{}:{}", kind, hex::encode(body))]
+pub struct FactorSourceIDFromHash {
+    kind: FactorSourceKind,
+    body: [u8; 32],
+}
+
+impl FactorSourceIDFromHash {
+    pub fn new(kind: FactorSourceKind, body: [u8; 32]) -> Self {
+        Self { kind, body }
+    }
+
+    pub fn kind(&self) -> FactorSourceKind {
+        self.kind
+    }
+
+    pub fn body(&self) -> [u8; 32] {
+        self.body
+    }
+
+    /// The ID of the factor source whose Ed25519 public key at
+    /// `CAP26GetIDPath` is `public_key`, fails unless `public_key` is a
+    /// valid 32 byte Ed25519 public key.
+    pub fn from_public_key(kind: FactorSourceKind, public_key: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes: [u8; 32] = public_key
+            .as_ref()
+            .try_into()
+            .map_err(|_| CommonError::InvalidEd25519PublicKey)?;
+        let public_key = Ed25519PublicKey::from_bytes(&bytes)?;
+        Ok(Self::new(kind, blake2b_256_hash(public_key.to_bytes())))
+    }
+
+    pub fn from_bip39_seed(kind: FactorSourceKind, seed: &BIP39Seed) -> Result<Self> {
+        let private_key = seed.derive_ed25519_private_key(CAP26GetIDPath)?;
+        Self::from_public_key(kind, private_key.public_key().to_bytes())
+    }
+
+    pub fn from_mnemonic_with_passphrase(
+        kind: FactorSourceKind,
+        mnemonic: &Mnemonic,
+        passphrase: impl AsRef<str>,
+    ) -> Result<Self> {
+        Self::from_bip39_seed(kind, &mnemonic.to_bip39_seed(passphrase.as_ref()))
+    }
+}
+
+impl FromStr for FactorSourceIDFromHash {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, body) = s
+            .split_once(':')
+            .ok_or(CommonError::InvalidFactorSourceIDFromHash)?;
+        let kind = FactorSourceKind::from_str(kind)?;
+        let body = hex::decode(body)
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or(CommonError::InvalidFactorSourceIDFromHash)?;
+        Ok(Self::new(kind, body))
+    }
+}
+
+impl HasSampleValues for FactorSourceIDFromHash {
+    fn sample() -> Self {
+        Self::from_str("device:f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a")
+            .unwrap()
+    }
+    fn sample_other() -> Self {
+        Self::from_str(
+            "ledgerHQHardwareWallet:ab59987eedd181fe98e512c1ba0f5ff059f11b5c7c56f15614dcc9fe03fec58b",
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = FactorSourceIDFromHash;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn same_body_different_kind_is_not_equal() {
+        let sut = Sut::sample();
+        assert_ne!(sut, Sut::new(FactorSourceKind::ArculusCard, sut.body()));
+    }
+
+    #[test]
+    fn from_mnemonic_with_passphrase() {
+        let mnemonic = Mnemonic::sample_other();
+        assert_eq!(
+            Sut::from_mnemonic_with_passphrase(FactorSourceKind::Device, &mnemonic, "").unwrap(),
+            Sut::sample()
+        );
+    }
+
+    #[test]
+    fn passphrase_changes_id() {
+        let mnemonic = Mnemonic::sample_other();
+        assert_ne!(
+            Sut::from_mnemonic_with_passphrase(FactorSourceKind::Device, &mnemonic, "radix")
+                .unwrap(),
+            Sut::sample()
+        );
+    }
+
+    #[test]
+    fn from_bip39_seed_and_public_key() {
+        let seed = BIP39Seed::sample_other();
+        let public_key = seed
+            .derive_ed25519_private_key(CAP26GetIDPath)
+            .unwrap()
+            .public_key();
+        assert_eq!(
+            Sut::from_bip39_seed(FactorSourceKind::Device, &seed).unwrap(),
+            Sut::from_public_key(FactorSourceKind::Device, public_key.to_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_public_key_wrong_length_is_err() {
+        assert_eq!(
+            Sut::from_public_key(FactorSourceKind::Device, [0xab; 33]),
+            Err(CommonError::InvalidEd25519PublicKey)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Sut::sample().to_string(),
+            "device:f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a"
+        );
+        assert_eq!(format!("{:?}", Sut::sample()), Sut::sample().to_string());
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        let sut = Sut::sample_other();
+        assert_eq!(Sut::from_str(&sut.to_string()).unwrap(), sut);
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in [
+            "f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a",
+            "device:f1a93d",
+            "device:zz",
+        ] {
+            assert_eq!(
+                Sut::from_str(s),
+                Err(CommonError::InvalidFactorSourceIDFromHash)
+            );
+        }
+        assert_eq!(
+            Sut::from_str("phone:f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a"),
+            Err(CommonError::InvalidFactorSourceKind)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Sut::sample(),
+            json!("device:f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a"),
+        );
+    }
+}
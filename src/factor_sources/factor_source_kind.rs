@@ -0,0 +1,123 @@
+use crate::prelude::*;
+
+/// The kind of a factor source, the origin of the keys used to control
+/// entities.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    MoreDebug,
+    SerializeDisplay,
+    DeserializeFromStr,
+)]
+pub enum FactorSourceKind {
+    /// A mnemonic stored on this device.
+    #[display("device")]
+    Device,
+
+    /// A Ledger hardware wallet.
+    #[display("ledgerHQHardwareWallet")]
+    LedgerHQHardwareWallet,
+
+    /// A mnemonic written down and not stored on any device.
+    #[display("offDeviceMnemonic")]
+    OffDeviceMnemonic,
+
+    /// An Arculus card.
+    #[display("arculusCard")]
+    ArculusCard,
+
+    /// A mnemonic derived from a password.
+    #[display("password")]
+    Password,
+
+    /// A mnemonic derived from answers to security questions.
+    #[display("securityQuestions")]
+    SecurityQuestions,
+
+    /// A contact trusted to help with recovery.
+    #[display("trustedContact")]
+    TrustedContact,
+}
+
+impl FactorSourceKind {
+    pub const ALL: [Self; 7] = [
+        Self::Device,
+        Self::LedgerHQHardwareWallet,
+        Self::OffDeviceMnemonic,
+        Self::ArculusCard,
+        Self::Password,
+        Self::SecurityQuestions,
+        Self::TrustedContact,
+    ];
+}
+
+impl FromStr for FactorSourceKind {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.to_string() == s)
+            .ok_or(CommonError::InvalidFactorSourceKind)
+    }
+}
+
+impl HasSampleValues for FactorSourceKind {
+    fn sample() -> Self {
+        Self::Device
+    }
+    fn sample_other() -> Self {
+        Self::LedgerHQHardwareWallet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = FactorSourceKind;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::OffDeviceMnemonic.to_string(), "offDeviceMnemonic");
+    }
+
+    #[test]
+    fn from_str_roundtrip_all() {
+        for kind in Sut::ALL {
+            assert_eq!(Sut::from_str(&kind.to_string()).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert_eq!(
+            Sut::from_str("Device"),
+            Err(CommonError::InvalidFactorSourceKind)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &Sut::LedgerHQHardwareWallet,
+            serde_json::json!("ledgerHQHardwareWallet"),
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use crate::prelude::*;
+
+/// A public key derived at a path of kind `P` from the factor source with
+/// `factor_source_id`, everything needed to know which factor source has
+/// to sign for it, and how. `P` is any `DerivationPath` by default, or one
+/// kind of path, e.g. `CAP26AccountPath`.
+///
+/// Serialized with the path as a `DerivationPath`, whichever `P` is.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct HierarchicalDeterministicFactorInstance<P = DerivationPath> {
+    factor_source_id: FactorSourceIDFromHash,
+    derivation_path: P,
+    public_key: PublicKey,
+}
+
+impl<P: HasSLIP10Curve> HierarchicalDeterministicFactorInstance<P> {
+    /// Fails if `public_key` is not on the curve dictated by the kind of
+    /// `derivation_path`.
+    pub fn new(
+        factor_source_id: FactorSourceIDFromHash,
+        derivation_path: P,
+        public_key: impl Into<PublicKey>,
+    ) -> Result<Self> {
+        let public_key = public_key.into();
+        if public_key.curve() != derivation_path.curve() {
+            return Err(CommonError::CurveMismatch {
+                expected: derivation_path.curve(),
+                found: public_key.curve(),
+            });
+        }
+        Ok(Self {
+            factor_source_id,
+            derivation_path,
+            public_key,
+        })
+    }
+
+    pub fn factor_source_id(&self) -> FactorSourceIDFromHash {
+        self.factor_source_id
+    }
+
+    pub fn derivation_path(&self) -> &P {
+        &self.derivation_path
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Whether `signature` is a valid signature of `message` by the public
+    /// key of this instance.
+    pub fn verify(&self, signature: &Signature, message: impl AsRef<[u8]>) -> bool {
+        self.public_key.verify(signature, message)
+    }
+}
+
+impl<P: HasSLIP10Curve + Clone + Into<DerivationPath>> HierarchicalDeterministicFactorInstance<P> {
+    /// Derives the public key at `derivation_path` from `seed`, identifying
+    /// `seed` as a factor source of `factor_source_kind`.
+    pub fn derive(
+        seed: &BIP39Seed,
+        factor_source_kind: FactorSourceKind,
+        derivation_path: P,
+    ) -> Result<Self> {
+        let factor_source_id = FactorSourceIDFromHash::from_bip39_seed(factor_source_kind, seed)?;
+        let public_key = seed
+            .derive_private_key(&derivation_path.clone().into())?
+            .public_key();
+        Self::new(factor_source_id, derivation_path, public_key)
+    }
+}
+
+/// The JSON representation of `HierarchicalDeterministicFactorInstance`,
+/// with the public key as hex, parsed on the curve of the derivation path.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HierarchicalDeterministicFactorInstanceJson {
+    factor_source_id: FactorSourceIDFromHash,
+    derivation_path: DerivationPath,
+    public_key: String,
+}
+
+impl<P: Into<DerivationPath>> From<HierarchicalDeterministicFactorInstance<P>>
+    for HierarchicalDeterministicFactorInstanceJson
+{
+    fn from(instance: HierarchicalDeterministicFactorInstance<P>) -> Self {
+        Self {
+            factor_source_id: instance.factor_source_id,
+            public_key: instance.public_key.to_hex(),
+            derivation_path: instance.derivation_path.into(),
+        }
+    }
+}
+
+impl<P> TryFrom<HierarchicalDeterministicFactorInstanceJson>
+    for HierarchicalDeterministicFactorInstance<P>
+where
+    P: HasSLIP10Curve + TryFrom<DerivationPath>,
+    CommonError: From<P::Error>,
+{
+    type Error = CommonError;
+    fn try_from(json: HierarchicalDeterministicFactorInstanceJson) -> Result<Self> {
+        let curve = json.derivation_path.curve();
+        let bytes = hex::decode(&json.public_key).map_err(|_| match curve {
+            SLIP10Curve::Curve25519 => CommonError::InvalidEd25519PublicKey,
+            SLIP10Curve::Secp256k1 => CommonError::InvalidSecp256k1PublicKey,
+        })?;
+        let public_key = PublicKey::from_bytes_on_curve(curve, bytes)?;
+        let derivation_path = P::try_from(json.derivation_path)?;
+        Self::new(json.factor_source_id, derivation_path, public_key)
+    }
+}
+
+impl<P: Clone + Into<DerivationPath>> serde::Serialize
+    for HierarchicalDeterministicFactorInstance<P>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HierarchicalDeterministicFactorInstanceJson::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de, P> serde::Deserialize<'de> for HierarchicalDeterministicFactorInstance<P>
+where
+    P: HasSLIP10Curve + TryFrom<DerivationPath>,
+    CommonError: From<P::Error>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = HierarchicalDeterministicFactorInstanceJson::deserialize(deserializer)?;
+        Self::try_from(json).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = HierarchicalDeterministicFactorInstance;
+
+    fn derive<P: HasSLIP10Curve + Clone + Into<DerivationPath>>(
+        path: P,
+    ) -> HierarchicalDeterministicFactorInstance<P> {
+        HierarchicalDeterministicFactorInstance::derive(
+            &BIP39Seed::sample(),
+            FactorSourceKind::Device,
+            path,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn derive_account() {
+        let path = CAP26AccountPath::from_str("m/44H/1022H/12H/525H/1460H/0H").unwrap();
+        let sut = derive(path.clone());
+        assert_eq!(sut.derivation_path(), &path);
+        assert_eq!(
+            sut.public_key().to_hex(),
+            "451152a1cef7be603205086d4ebac0a0b78fda2ff4684b9dea5ca9ef003d4e7d"
+        );
+        assert_eq!(
+            sut.factor_source_id(),
+            FactorSourceIDFromHash::from_bip39_seed(FactorSourceKind::Device, &BIP39Seed::sample())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_identity() {
+        let sut = derive(CAP26IdentityPath::sample());
+        assert_eq!(sut.public_key().curve(), SLIP10Curve::Curve25519);
+        assert_eq!(sut.derivation_path(), &CAP26IdentityPath::sample());
+    }
+
+    #[test]
+    fn derive_any_derivation_path() {
+        let sut = derive(DerivationPath::from(CAP26AccountPath::sample()));
+        assert_eq!(
+            sut.public_key(),
+            derive(CAP26AccountPath::sample()).public_key()
+        );
+    }
+
+    #[test]
+    fn derive_bip44_like() {
+        let sut = derive(BIP44LikePath::from_str("m/44H/1022H/0H/0/0").unwrap());
+        assert_eq!(
+            sut.public_key().to_hex(),
+            "03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f"
+        );
+    }
+
+    #[test]
+    fn new_curve_mismatch_is_err() {
+        let secp256k1 = derive(BIP44LikePath::sample());
+        assert_eq!(
+            HierarchicalDeterministicFactorInstance::new(
+                secp256k1.factor_source_id(),
+                CAP26AccountPath::sample(),
+                secp256k1.public_key()
+            ),
+            Err(CommonError::CurveMismatch {
+                expected: SLIP10Curve::Curve25519,
+                found: SLIP10Curve::Secp256k1,
+            })
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let seed = BIP39Seed::sample();
+        for path in [
+            DerivationPath::from(CAP26AccountPath::sample()),
+            DerivationPath::from(CAP26IdentityPath::sample()),
+            DerivationPath::from(BIP44LikePath::sample()),
+        ] {
+            let sut = Sut::derive(&seed, FactorSourceKind::Device, path.clone()).unwrap();
+            let signature = seed.derive_private_key(&path).unwrap().sign("Hello Radix");
+            assert!(sut.verify(&signature, "Hello Radix"));
+            assert!(!sut.verify(&signature, "Hello Olympia"));
+        }
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = derive(BIP44LikePath::from_str("m/44H/1022H/0H/0/0").unwrap());
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "factorSourceId": sut.factor_source_id().to_string(),
+                "derivationPath": "m/44H/1022H/0H/0/0",
+                "publicKey": "03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f"
+            }),
+        );
+        assert_json_roundtrip(&derive(CAP26AccountPath::sample()));
+        assert_json_roundtrip(&derive(DerivationPath::from(CAP26AccountPath::sample())));
+    }
+
+    #[test]
+    fn json_same_for_any_kind_of_path() {
+        let path = CAP26IdentityPath::sample();
+        assert_eq!(
+            serde_json::to_value(derive(path.clone())).unwrap(),
+            serde_json::to_value(derive(DerivationPath::from(path))).unwrap()
+        );
+    }
+
+    #[test]
+    fn json_wrong_kind_of_path_is_err() {
+        let json = serde_json::to_value(derive(CAP26IdentityPath::sample())).unwrap();
+        assert!(serde_json::from_value::<Sut>(json.clone()).is_ok());
+        assert!(
+            serde_json::from_value::<HierarchicalDeterministicFactorInstance<CAP26IdentityPath>>(
+                json.clone()
+            )
+            .is_ok()
+        );
+        assert!(
+            serde_json::from_value::<HierarchicalDeterministicFactorInstance<CAP26AccountPath>>(
+                json
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn json_public_key_on_wrong_curve_is_err() {
+        let json = json!({
+            "factorSourceId": FactorSourceIDFromHash::sample().to_string(),
+            "derivationPath": "m/44H/1022H/1H/525H/1460H/0H",
+            "publicKey": "03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f"
+        });
+        assert!(serde_json::from_value::<Sut>(json).is_err());
+    }
+}
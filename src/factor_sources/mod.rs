@@ -0,0 +1,11 @@
+#[cfg(feature = "std")]
+mod factor_source_id_from_hash;
+mod factor_source_kind;
+#[cfg(feature = "std")]
+mod hierarchical_deterministic_factor_instance;
+
+#[cfg(feature = "std")]
+pub use factor_source_id_from_hash::*;
+pub use factor_source_kind::*;
+#[cfg(feature = "std")]
+pub use hierarchical_deterministic_factor_instance::*;
@@ -0,0 +1,214 @@
+use crate::prelude::*;
+
+use crypto::ciphers::{chacha::XChaCha20Poly1305, traits::Aead};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// A message encrypted with XChaCha20Poly1305 under a key derived with
+/// HKDF-SHA256 from an `X25519SharedSecret`, so that only the two parties of
+/// the key agreement can decrypt it, and any tampering is detected.
+///
+/// Displayed and serialized as the hex of `nonce || ciphertext || tag`.
+#[derive(Clone, PartialEq, Eq, Hash, Display, MoreDebug, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", self.to_hex())]
+#[debug("{}", self.to_hex())]
+pub struct EncryptedMessage {
+    nonce: [u8; Self::NONCE_LENGTH],
+    ciphertext: Vec<u8>,
+    tag: [u8; Self::TAG_LENGTH],
+}
+
+impl EncryptedMessage {
+    const NONCE_LENGTH: usize = 24;
+    const TAG_LENGTH: usize = 16;
+    const KEY_INFO: &'static [u8] = b"MessageEncryption";
+
+    fn key(shared_secret: &X25519SharedSecret) -> Zeroizing<[u8; 32]> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        Hkdf::<Sha256>::new(None, shared_secret.as_bytes())
+            .expand(Self::KEY_INFO, key.as_mut())
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        key
+    }
+
+    /// Encrypts `plaintext` with a random nonce, fails if the OS random
+    /// number generator is unavailable.
+    pub fn encrypt(
+        plaintext: impl AsRef<[u8]>,
+        shared_secret: &X25519SharedSecret,
+    ) -> Result<Self> {
+        let mut nonce = [0u8; Self::NONCE_LENGTH];
+        getrandom::getrandom(&mut nonce).map_err(|_| CommonError::SecureRandomnessUnavailable)?;
+        Ok(Self::encrypt_with_nonce(
+            plaintext.as_ref(),
+            shared_secret,
+            nonce,
+        ))
+    }
+
+    fn encrypt_with_nonce(
+        plaintext: &[u8],
+        shared_secret: &X25519SharedSecret,
+        nonce: [u8; Self::NONCE_LENGTH],
+    ) -> Self {
+        let mut ciphertext = vec![0u8; plaintext.len()];
+        let mut tag = [0u8; Self::TAG_LENGTH];
+        XChaCha20Poly1305::try_encrypt(
+            Self::key(shared_secret).as_ref(),
+            &nonce,
+            &[],
+            plaintext,
+            &mut ciphertext,
+            &mut tag,
+        )
+        .expect("Key, nonce and tag have the lengths of XChaCha20Poly1305");
+        Self {
+            nonce,
+            ciphertext,
+            tag,
+        }
+    }
+
+    /// Decrypts this message, fails if it was not encrypted with
+    /// `shared_secret` or has been tampered with.
+    pub fn decrypt(&self, shared_secret: &X25519SharedSecret) -> Result<Vec<u8>> {
+        let mut plaintext = vec![0u8; self.ciphertext.len()];
+        XChaCha20Poly1305::try_decrypt(
+            Self::key(shared_secret).as_ref(),
+            &self.nonce,
+            &[],
+            &mut plaintext,
+            &self.ciphertext,
+            &self.tag,
+        )
+        .map_err(|_| CommonError::MessageDecryptionFailed)?;
+        Ok(plaintext)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [&self.nonce[..], &self.ciphertext, &self.tag].concat()
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+
+    /// Parses `nonce || ciphertext || tag`, fails if too short to contain
+    /// the nonce and tag.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        if bytes.len() < Self::NONCE_LENGTH + Self::TAG_LENGTH {
+            return Err(CommonError::InvalidEncryptedMessage);
+        }
+        let (nonce, rest) = bytes.split_at(Self::NONCE_LENGTH);
+        let (ciphertext, tag) = rest.split_at(rest.len() - Self::TAG_LENGTH);
+        Ok(Self {
+            nonce: nonce.try_into().unwrap(),
+            ciphertext: ciphertext.to_vec(),
+            tag: tag.try_into().unwrap(),
+        })
+    }
+}
+
+impl FromStr for EncryptedMessage {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        hex::decode(s)
+            .map_err(|_| CommonError::InvalidEncryptedMessage)
+            .and_then(Self::from_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = EncryptedMessage;
+
+    fn key(index: u32) -> X25519PrivateKey {
+        BIP39Seed::sample()
+            .derive_x25519_private_key(CAP26AccountPath::new(
+                NetworkID::Mainnet,
+                CAP26KeyKind::MessageEncryption,
+                Hardened::from_local_key_space(index, IsSecurified(false)).unwrap(),
+            ))
+            .unwrap()
+    }
+
+    fn shared_secrets() -> (X25519SharedSecret, X25519SharedSecret) {
+        let (alice, bob) = (key(0), key(1));
+        (
+            alice.diffie_hellman(&bob.public_key()).unwrap(),
+            bob.diffie_hellman(&alice.public_key()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn encrypt_decrypt_between_accounts() {
+        let (alice, bob) = shared_secrets();
+        assert_eq!(alice, bob);
+        let sut = Sut::encrypt("Hello Bob", &alice).unwrap();
+        assert_eq!(sut.decrypt(&bob).unwrap(), b"Hello Bob");
+    }
+
+    #[test]
+    fn encrypt_is_randomized() {
+        let (alice, _) = shared_secrets();
+        assert_ne!(
+            Sut::encrypt("Hi", &alice).unwrap(),
+            Sut::encrypt("Hi", &alice).unwrap()
+        );
+    }
+
+    #[test]
+    fn decrypt_with_other_secret_is_err() {
+        let (alice, _) = shared_secrets();
+        let eve = key(2).diffie_hellman(&key(1).public_key()).unwrap();
+        let sut = Sut::encrypt("Hello Bob", &alice).unwrap();
+        assert_eq!(sut.decrypt(&eve), Err(CommonError::MessageDecryptionFailed));
+    }
+
+    #[test]
+    fn decrypt_tampered_is_err() {
+        let (alice, bob) = shared_secrets();
+        let mut bytes = Sut::encrypt("Hello Bob", &alice).unwrap().to_bytes();
+        bytes[30] ^= 1;
+        assert_eq!(
+            Sut::from_bytes(bytes).unwrap().decrypt(&bob),
+            Err(CommonError::MessageDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn fixed_nonce() {
+        let (alice, _) = shared_secrets();
+        let sut = Sut::encrypt_with_nonce(b"Hello Bob", &alice, [7; 24]);
+        assert_eq!(sut.to_bytes().len(), 24 + 9 + 16);
+        assert_eq!(&sut.to_bytes()[..24], &[7; 24]);
+        assert_eq!(sut, Sut::encrypt_with_nonce(b"Hello Bob", &alice, [7; 24]));
+    }
+
+    #[test]
+    fn empty_message() {
+        let (alice, bob) = shared_secrets();
+        let sut = Sut::encrypt([], &alice).unwrap();
+        assert_eq!(sut.decrypt(&bob).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn from_bytes_too_short() {
+        assert_eq!(
+            Sut::from_bytes([0u8; 39]),
+            Err(CommonError::InvalidEncryptedMessage)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let (alice, _) = shared_secrets();
+        let sut = Sut::encrypt("Hello Bob", &alice).unwrap();
+        assert_json_value_eq_after_roundtrip(&sut, serde_json::json!(sut.to_hex()));
+        assert_eq!(Sut::from_str(&sut.to_hex()).unwrap(), sut);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::prelude::*;
+
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// Version bytes of a mainnet extended private key, encoding as `xprv...`.
+pub const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+
+/// Version bytes of a mainnet extended public key, encoding as `xpub...`.
+pub const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
+/// The 78 bytes of a BIP32 serialized extended key, before Base58Check
+/// encoding.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct ExtendedKeyPayload {
+    pub(crate) version: [u8; 4],
+    pub(crate) depth: u8,
+    pub(crate) parent_fingerprint: [u8; 4],
+    pub(crate) child_number: HDPathComponent,
+    pub(crate) chain_code: [u8; 32],
+    /// `0x00` followed by the private key, or a compressed public key.
+    pub(crate) key_data: [u8; 33],
+}
+
+impl ExtendedKeyPayload {
+    pub(crate) const LENGTH: usize = 78;
+
+    pub(crate) fn to_base58check(&self) -> String {
+        let mut bytes = [0u8; Self::LENGTH];
+        bytes[0..4].copy_from_slice(&self.version);
+        bytes[4] = self.depth;
+        bytes[5..9].copy_from_slice(&self.parent_fingerprint);
+        bytes[9..13].copy_from_slice(&self.child_number.map_to_global_key_space().to_be_bytes());
+        bytes[13..45].copy_from_slice(&self.chain_code);
+        bytes[45..78].copy_from_slice(&self.key_data);
+        let encoded = bs58::encode(bytes).with_check().into_string();
+        zeroize::Zeroize::zeroize(&mut bytes);
+        encoded
+    }
+
+    /// Decodes `s` and checks that it has the `expected_version`, and that
+    /// the fingerprint and child number of a master key, of depth 0, are 0.
+    pub(crate) fn from_base58check(s: &str, expected_version: [u8; 4]) -> Result<Self> {
+        let bytes = zeroize::Zeroizing::new(
+            bs58::decode(s)
+                .with_check(None)
+                .into_vec()
+                .map_err(|_| CommonError::InvalidBase58Check)?,
+        );
+        if bytes.len() != Self::LENGTH {
+            return Err(CommonError::InvalidExtendedKeyLength {
+                expected: Self::LENGTH,
+                found: bytes.len(),
+            });
+        }
+        let version: [u8; 4] = bytes[0..4].try_into().unwrap();
+        if version != expected_version {
+            return Err(CommonError::InvalidExtendedKeyVersion);
+        }
+        let payload = Self {
+            version,
+            depth: bytes[4],
+            parent_fingerprint: bytes[5..9].try_into().unwrap(),
+            child_number: HDPathComponent::from_global(u32::from_be_bytes(
+                bytes[9..13].try_into().unwrap(),
+            )),
+            chain_code: bytes[13..45].try_into().unwrap(),
+            key_data: bytes[45..78].try_into().unwrap(),
+        };
+        if payload.depth == 0
+            && (payload.parent_fingerprint != [0; 4]
+                || payload.child_number.map_to_global_key_space() != 0)
+        {
+            return Err(CommonError::InvalidExtendedKeyMasterFields);
+        }
+        Ok(payload)
+    }
+}
+
+/// RIPEMD160 of SHA256 of `data`, used for key fingerprints.
+pub(crate) fn hash160(data: impl AsRef<[u8]>) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// The first 4 bytes of HASH160 of the compressed `public_key`, identifying
+/// an extended key as the parent of its children.
+pub(crate) fn fingerprint(public_key: &Secp256k1PublicKey) -> [u8; 4] {
+    let hash = hash160(public_key.to_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash160_of_empty() {
+        assert_eq!(
+            hex::encode(hash160([])),
+            "b472a266d0bd89c13706a4132ccfb16f7c3b9fcb"
+        );
+    }
+
+    #[test]
+    fn invalid_checksum_is_err() {
+        assert_eq!(
+            ExtendedKeyPayload::from_base58check(
+                "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduA",
+                XPUB_VERSION
+            )
+            .err(),
+            Some(CommonError::InvalidBase58Check)
+        );
+    }
+
+    #[test]
+    fn wrong_length_is_err() {
+        let s = bs58::encode([0u8; 77]).with_check().into_string();
+        assert_eq!(
+            ExtendedKeyPayload::from_base58check(&s, XPUB_VERSION).err(),
+            Some(CommonError::InvalidExtendedKeyLength {
+                expected: 78,
+                found: 77
+            })
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::prelude::*;
+
+/// A BIP32 extended Secp256k1 private key, a private key together with its
+/// chain code and position in the derivation tree, serializable as an
+/// `xprv...` Base58Check string.
+///
+/// Does not implement `Display` or `Debug`, to not leak the private key
+/// into logs, use `to_base58check_string` explicitly. Zeroized on drop.
+#[derive(Clone, zeroize::ZeroizeOnDrop)]
+pub struct ExtendedPrivateKey {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    #[zeroize(skip)]
+    child_number: HDPathComponent,
+    chain_code: [u8; 32],
+    private_key: Secp256k1PrivateKey,
+}
+
+impl ExtendedPrivateKey {
+    pub(crate) fn new(
+        depth: u8,
+        parent_fingerprint: [u8; 4],
+        child_number: HDPathComponent,
+        chain_code: [u8; 32],
+        private_key: Secp256k1PrivateKey,
+    ) -> Self {
+        Self {
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            private_key,
+        }
+    }
+
+    /// The number of derivation steps from the master key, `0` for the
+    /// master key itself.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// The first 4 bytes of HASH160 of the parent public key, `0` for the
+    /// master key.
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// The last component of the path this key was derived at, `0` for the
+    /// master key.
+    pub fn child_number(&self) -> HDPathComponent {
+        self.child_number
+    }
+
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    pub fn private_key(&self) -> &Secp256k1PrivateKey {
+        &self.private_key
+    }
+
+    /// The extended public key of this key, at the same position in the
+    /// derivation tree.
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey::new(
+            self.depth,
+            self.parent_fingerprint,
+            self.child_number,
+            self.chain_code,
+            self.private_key.public_key(),
+        )
+    }
+
+    /// The first 4 bytes of HASH160 of the public key, identifying this key
+    /// as the parent of its children.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.extended_public_key().fingerprint()
+    }
+
+    fn to_payload(&self) -> ExtendedKeyPayload {
+        let mut key_data = [0u8; 33];
+        key_data[1..].copy_from_slice(&*self.private_key.to_bytes());
+        ExtendedKeyPayload {
+            version: XPRV_VERSION,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key_data,
+        }
+    }
+
+    /// The BIP32 serialization of this key, as an `xprv...` Base58Check
+    /// string.
+    pub fn to_base58check_string(&self) -> String {
+        let mut payload = self.to_payload();
+        let encoded = payload.to_base58check();
+        zeroize::Zeroize::zeroize(&mut payload.key_data);
+        encoded
+    }
+
+    /// Parses a BIP32 `xprv...` Base58Check string.
+    pub fn from_base58check_string(s: impl AsRef<str>) -> Result<Self> {
+        let mut payload = ExtendedKeyPayload::from_base58check(s.as_ref(), XPRV_VERSION)?;
+        let key = if payload.key_data[0] == 0 {
+            Secp256k1PrivateKey::from_bytes(payload.key_data[1..].try_into().unwrap())
+        } else {
+            Err(CommonError::InvalidSecp256k1PrivateKey)
+        };
+        zeroize::Zeroize::zeroize(&mut payload.key_data);
+        Ok(Self::new(
+            payload.depth,
+            payload.parent_fingerprint,
+            payload.child_number,
+            payload.chain_code,
+            key?,
+        ))
+    }
+}
+
+impl FromStr for ExtendedPrivateKey {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_base58check_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = ExtendedPrivateKey;
+
+    /// BIP32 test vector 2
+    fn seed() -> BIP39Seed {
+        let bytes = hex::decode("fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542").unwrap();
+        BIP39Seed::from_bytes(bytes.try_into().unwrap())
+    }
+
+    fn derive(path: &str) -> Sut {
+        seed()
+            .derive_extended_private_key(HDPath::from_str(path).unwrap())
+            .unwrap()
+    }
+
+    fn assert_vector(path: &str, xprv: &str, xpub: &str) {
+        let sut = derive(path);
+        assert_eq!(sut.to_base58check_string(), xprv);
+        assert_eq!(sut.extended_public_key().to_string(), xpub);
+    }
+
+    #[test]
+    fn bip32_vector_2_master() {
+        assert_vector(
+            "m",
+            "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+            "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB",
+        );
+    }
+
+    #[test]
+    fn bip32_vector_2_m_0() {
+        assert_vector(
+            "m/0",
+            "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+            "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH",
+        );
+    }
+
+    #[test]
+    fn bip32_vector_2_m_0_max_hardened() {
+        assert_vector(
+            "m/0/1073741823S",
+            "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+            "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a",
+        );
+    }
+
+    #[test]
+    fn bip32_vector_2_m_0_max_hardened_1() {
+        assert_vector(
+            "m/0/1073741823S/1",
+            "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+            "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon",
+        );
+    }
+
+    #[test]
+    fn bip39_trezor_vector_master() {
+        let seed = bip39::Mnemonic::from_str(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap()
+        .to_bip39_seed("TREZOR");
+        assert_eq!(
+            seed.derive_extended_private_key(HDPath::empty(HDPathRoot::Private))
+                .unwrap()
+                .to_base58check_string(),
+            "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF"
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let parent = derive("m/0");
+        let sut = derive("m/0/1073741823S");
+        assert_eq!(sut.depth(), 2);
+        assert_eq!(sut.parent_fingerprint(), parent.fingerprint());
+        assert_eq!(
+            sut.child_number(),
+            HDPathComponent::from_str("1073741823S").unwrap()
+        );
+        assert_eq!(derive("m").parent_fingerprint(), [0; 4]);
+    }
+
+    #[test]
+    fn private_key_is_leaf_key() {
+        let path = BIP44LikePath::sample();
+        let sut = seed().derive_extended_private_key(path.clone()).unwrap();
+        assert_eq!(
+            sut.private_key().to_hex(),
+            seed().derive_secp256k1_private_key(path).unwrap().to_hex()
+        );
+    }
+
+    #[test]
+    fn base58check_roundtrip() {
+        let sut = derive("m/0/1073741823S/1");
+        let s = sut.to_base58check_string();
+        let parsed = Sut::from_str(&s).unwrap();
+        assert_eq!(parsed.to_base58check_string(), s);
+        assert_eq!(parsed.depth(), 3);
+        assert_eq!(parsed.chain_code(), sut.chain_code());
+    }
+
+    #[test]
+    fn from_xpub_is_err() {
+        let xpub = derive("m/0").extended_public_key().to_string();
+        assert!(matches!(
+            Sut::from_str(&xpub),
+            Err(CommonError::InvalidExtendedKeyVersion)
+        ));
+    }
+
+    #[test]
+    fn public_root_is_err() {
+        assert!(matches!(
+            seed().derive_extended_private_key(HDPath::from_str("M/0").unwrap()),
+            Err(CommonError::PrivateKeyDerivationFromPublicRoot)
+        ));
+    }
+}
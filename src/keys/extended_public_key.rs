@@ -0,0 +1,270 @@
+use crate::prelude::*;
+
+/// A BIP32 extended Secp256k1 public key, a public key together with its
+/// chain code and position in the derivation tree, serializable as an
+/// `xpub...` Base58Check string, e.g. for watch-only wallets.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Display, MoreDebug, SerializeDisplay, DeserializeFromStr,
+)]
+#[display("{}", self.to_base58check_string())]
+#[debug("{}", self.to_base58check_string())]
+pub struct ExtendedPublicKey {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: HDPathComponent,
+    chain_code: [u8; 32],
+    public_key: Secp256k1PublicKey,
+}
+
+impl ExtendedPublicKey {
+    pub(crate) fn new(
+        depth: u8,
+        parent_fingerprint: [u8; 4],
+        child_number: HDPathComponent,
+        chain_code: [u8; 32],
+        public_key: Secp256k1PublicKey,
+    ) -> Self {
+        Self {
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+            public_key,
+        }
+    }
+
+    /// The number of derivation steps from the master key, `0` for the
+    /// master key itself.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// The first 4 bytes of HASH160 of the parent public key, `0` for the
+    /// master key.
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// The last component of the path this key was derived at, `0` for the
+    /// master key.
+    pub fn child_number(&self) -> HDPathComponent {
+        self.child_number
+    }
+
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    pub fn public_key(&self) -> Secp256k1PublicKey {
+        self.public_key
+    }
+
+    /// The first 4 bytes of HASH160 of the public key, identifying this key
+    /// as the parent of its children.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+
+    /// BIP32 CKDpub, the extended public key of the child at `index`,
+    /// derivable without the private key. Fails if this key already is of
+    /// the max BIP32 depth 255.
+    pub fn derive_child(&self, index: Unhardened) -> Result<Self> {
+        let depth = self.depth.checked_add(1).ok_or(CommonError::Overflow)?;
+        let (public_key, chain_code) = self.public_key.child_key(&self.chain_code, index);
+        Ok(Self::new(
+            depth,
+            self.fingerprint(),
+            HDPathComponent::from(index),
+            chain_code,
+            public_key,
+        ))
+    }
+
+    /// Like `derive_child` but for any `HDPathComponent`, fails if it is
+    /// hardened or securified, since those require the private key.
+    pub fn derive_child_component(&self, component: HDPathComponent) -> Result<Self> {
+        match component.as_unsecurified() {
+            Some(Unsecurified::Unhardened(index)) => self.derive_child(index),
+            _ => Err(CommonError::HardenedComponentInPublicKeyDerivation { component }),
+        }
+    }
+
+    /// Derives each component of `path` in turn, e.g. the unhardened tail
+    /// `0/5` of a `BIP44LikePath` from the xpub of `m/44H/1022H/0H`.
+    pub fn derive_path(&self, path: &RelativeHDPath) -> Result<Self> {
+        path.into_iter().try_fold(*self, |key, component| {
+            key.derive_child_component(component)
+        })
+    }
+
+    /// The BIP32 serialization of this key, as an `xpub...` Base58Check
+    /// string.
+    pub fn to_base58check_string(&self) -> String {
+        ExtendedKeyPayload {
+            version: XPUB_VERSION,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key_data: self.public_key.to_bytes(),
+        }
+        .to_base58check()
+    }
+
+    /// Parses a BIP32 `xpub...` Base58Check string.
+    pub fn from_base58check_string(s: impl AsRef<str>) -> Result<Self> {
+        let payload = ExtendedKeyPayload::from_base58check(s.as_ref(), XPUB_VERSION)?;
+        Ok(Self::new(
+            payload.depth,
+            payload.parent_fingerprint,
+            payload.child_number,
+            payload.chain_code,
+            Secp256k1PublicKey::from_bytes(&payload.key_data)?,
+        ))
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_base58check_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = ExtendedPublicKey;
+
+    /// BIP32 test vector 2, `m/0`
+    const XPUB: &str = "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH";
+
+    #[test]
+    fn from_str() {
+        let sut = Sut::from_str(XPUB).unwrap();
+        assert_eq!(sut.depth(), 1);
+        assert_eq!(sut.child_number(), HDPathComponent::from_str("0").unwrap());
+        assert_eq!(sut.to_string(), XPUB);
+    }
+
+    #[test]
+    fn debug_is_display() {
+        let sut = Sut::from_str(XPUB).unwrap();
+        assert_eq!(format!("{:?}", sut), XPUB);
+    }
+
+    #[test]
+    fn fingerprint_of_parent() {
+        let master = Sut::from_str("xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB").unwrap();
+        let sut = Sut::from_str(XPUB).unwrap();
+        assert_eq!(sut.parent_fingerprint(), master.fingerprint());
+        assert_eq!(hex::encode(master.fingerprint()), "bd16bee5");
+    }
+
+    #[test]
+    fn from_xprv_is_err() {
+        assert!(matches!(
+            Sut::from_str("xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt"),
+            Err(CommonError::InvalidExtendedKeyVersion)
+        ));
+    }
+
+    #[test]
+    fn invalid_public_key_is_err() {
+        // BIP32 test vector 5, pubkey version / prvkey mismatch
+        assert!(matches!(
+            Sut::from_str("xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6LBpB85b3D2yc8sfvZU521AAwdZafEz7mnzBBsz4wKY5fTtTQBm"),
+            Err(CommonError::InvalidSecp256k1PublicKey)
+        ));
+    }
+
+    #[test]
+    fn master_with_parent_fingerprint_is_err() {
+        // BIP32 test vector 5, zero depth with non-zero parent fingerprint
+        assert!(matches!(
+            Sut::from_str("xpub661no6RGEX3uJkY4bNnPcw4URcQTrSibUZ4NqJEw5eBkv7ovTwgiT91XX27VbEXGENhYRCf7hyEbWrR3FewATdCEebj6znwMfQkhRYHRLpJ"),
+            Err(CommonError::InvalidExtendedKeyMasterFields)
+        ));
+    }
+
+    /// BIP32 test vector 1, `m/0H` and `m/0H/1`
+    #[test]
+    fn derive_child_bip32_vector_1() {
+        let parent = Sut::from_str("xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw").unwrap();
+        assert_eq!(
+            parent.derive_child(Unhardened::ONE).unwrap().to_string(),
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ"
+        );
+    }
+
+    #[test]
+    fn derive_path_of_bip44_like_path_matches_private_derivation() {
+        let seed = bip39::Mnemonic::from_str(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap()
+        .to_bip39_seed("");
+        let account = seed
+            .derive_extended_private_key(HDPath::from_str("m/44H/1022H/0H").unwrap())
+            .unwrap()
+            .extended_public_key();
+        let index = Unhardened::from_local_key_space(5u32).unwrap();
+        let path = BIP44LikePath::new(HDPathComponent::from(index));
+        assert_eq!(
+            account
+                .derive_path(&RelativeHDPath::from_str("0/5").unwrap())
+                .unwrap(),
+            seed.derive_extended_private_key(path)
+                .unwrap()
+                .extended_public_key()
+        );
+    }
+
+    #[test]
+    fn derive_child_component_hardened_is_err() {
+        let sut = Sut::from_str(XPUB).unwrap();
+        for s in ["5H", "5S"] {
+            let component = HDPathComponent::from_str(s).unwrap();
+            assert_eq!(
+                sut.derive_child_component(component),
+                Err(CommonError::HardenedComponentInPublicKeyDerivation { component })
+            );
+        }
+    }
+
+    #[test]
+    fn derive_path_hardened_is_err() {
+        let sut = Sut::from_str(XPUB).unwrap();
+        assert_eq!(
+            sut.derive_path(&RelativeHDPath::from_str("0/5H").unwrap()),
+            Err(CommonError::HardenedComponentInPublicKeyDerivation {
+                component: HDPathComponent::from_str("5H").unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn derive_child_at_max_depth_is_err() {
+        let sut = Sut::new(
+            u8::MAX,
+            [1; 4],
+            HDPathComponent::from_global(0),
+            [0; 32],
+            Sut::from_str(XPUB).unwrap().public_key(),
+        );
+        assert_eq!(
+            sut.derive_child(Unhardened::ZERO),
+            Err(CommonError::Overflow)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::from_str(XPUB).unwrap();
+        assert_json_value_eq_after_roundtrip(&sut, json!(XPUB));
+        assert_json_roundtrip(&sut);
+    }
+}
@@ -0,0 +1,15 @@
+mod encrypted_message;
+mod extended_key_payload;
+mod extended_private_key;
+mod extended_public_key;
+mod private_key;
+mod public_key;
+mod signature;
+
+pub use encrypted_message::*;
+pub(crate) use extended_key_payload::*;
+pub use extended_private_key::*;
+pub use extended_public_key::*;
+pub use private_key::*;
+pub use public_key::*;
+pub use signature::*;
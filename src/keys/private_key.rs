@@ -0,0 +1,184 @@
+use crate::prelude::*;
+
+use zeroize::Zeroizing;
+
+/// A private key on either of the curves keys are derived on, as dictated
+/// by the kind of derivation path, see `BIP39Seed::derive_private_key`.
+#[derive(Clone, EnumAsInner)]
+pub enum PrivateKey {
+    Ed25519(Ed25519PrivateKey),
+    Secp256k1(Secp256k1PrivateKey),
+}
+
+impl PrivateKey {
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Self::Ed25519(key) => PublicKey::Ed25519(key.public_key()),
+            Self::Secp256k1(key) => PublicKey::Secp256k1(key.public_key()),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
+        match self {
+            Self::Ed25519(key) => key.to_bytes(),
+            Self::Secp256k1(key) => key.to_bytes(),
+        }
+    }
+
+    /// Signs `message`, see `Ed25519PrivateKey::sign` and
+    /// `Secp256k1PrivateKey::sign`.
+    pub fn sign(&self, message: impl AsRef<[u8]>) -> Signature {
+        match self {
+            Self::Ed25519(key) => key.sign(message).into(),
+            Self::Secp256k1(key) => key.sign(message).into(),
+        }
+    }
+
+    pub fn to_hex(&self) -> String {
+        match self {
+            Self::Ed25519(key) => key.to_hex(),
+            Self::Secp256k1(key) => key.to_hex(),
+        }
+    }
+}
+
+impl HasSLIP10Curve for PrivateKey {
+    fn curve(&self) -> SLIP10Curve {
+        match self {
+            Self::Ed25519(_) => SLIP10Curve::Curve25519,
+            Self::Secp256k1(_) => SLIP10Curve::Secp256k1,
+        }
+    }
+}
+
+impl From<Ed25519PrivateKey> for PrivateKey {
+    fn from(key: Ed25519PrivateKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+impl From<Secp256k1PrivateKey> for PrivateKey {
+    fn from(key: Secp256k1PrivateKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl TryFrom<PrivateKey> for Ed25519PrivateKey {
+    type Error = CommonError;
+    fn try_from(key: PrivateKey) -> Result<Self> {
+        let found = key.curve();
+        key.into_ed25519().map_err(|_| CommonError::CurveMismatch {
+            expected: SLIP10Curve::Curve25519,
+            found,
+        })
+    }
+}
+
+impl TryFrom<PrivateKey> for Secp256k1PrivateKey {
+    type Error = CommonError;
+    fn try_from(key: PrivateKey) -> Result<Self> {
+        let found = key.curve();
+        key.into_secp256k1()
+            .map_err(|_| CommonError::CurveMismatch {
+                expected: SLIP10Curve::Secp256k1,
+                found,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = PrivateKey;
+
+    fn derive(path: &str) -> Sut {
+        BIP39Seed::sample()
+            .derive_private_key(&DerivationPath::from_str(path).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn account_path_is_ed25519() {
+        let sut = derive("m/44H/1022H/12H/525H/1460H/0H");
+        assert_eq!(sut.curve(), SLIP10Curve::Curve25519);
+        assert_eq!(
+            sut.to_hex(),
+            "13e971fb16cb2c816d6b9f12176e9b8ab9af1831d006114d344d119ab2715506"
+        );
+        assert_eq!(
+            sut.public_key().to_hex(),
+            "451152a1cef7be603205086d4ebac0a0b78fda2ff4684b9dea5ca9ef003d4e7d"
+        );
+    }
+
+    #[test]
+    fn bip44_like_path_is_secp256k1() {
+        let sut = derive("m/44H/1022H/0H/0/0");
+        assert_eq!(sut.curve(), SLIP10Curve::Secp256k1);
+        assert_eq!(
+            sut.to_hex(),
+            "623048f7bb88a4d162442b88cdd80c85e4d5933ad9e78523a97de769badb9ab2"
+        );
+        assert_eq!(
+            sut.public_key().to_hex(),
+            "03bc2ec8f3668c869577bf66b7b48f8dee57b833916aa70966fa4a5029b63bb18f"
+        );
+    }
+
+    #[test]
+    fn bytes_is_hex() {
+        let sut = derive("m/44H/1022H/0H/0/0");
+        assert_eq!(hex::encode(*sut.to_bytes()), sut.to_hex());
+    }
+
+    #[test]
+    fn mismatched_curve_is_err() {
+        let path = DerivationPath::from_str("m/44H/1022H/0H/0/0").unwrap();
+        assert!(matches!(
+            BIP39Seed::sample().derive_private_key_on_curve(&path, SLIP10Curve::Curve25519),
+            Err(CommonError::CurveMismatch {
+                expected: SLIP10Curve::Secp256k1,
+                found: SLIP10Curve::Curve25519,
+            })
+        ));
+    }
+
+    #[test]
+    fn matching_curve_is_ok() {
+        let path = DerivationPath::from_str("m/44H/1022H/0H/0/0").unwrap();
+        assert_eq!(
+            BIP39Seed::sample()
+                .derive_private_key_on_curve(&path, SLIP10Curve::Secp256k1)
+                .unwrap()
+                .to_hex(),
+            derive("m/44H/1022H/0H/0/0").to_hex()
+        );
+    }
+
+    #[test]
+    fn try_into_ed25519() {
+        let sut = derive("m/44H/1022H/12H/525H/1460H/0H");
+        assert!(Ed25519PrivateKey::try_from(sut.clone()).is_ok());
+        assert!(matches!(
+            Secp256k1PrivateKey::try_from(sut),
+            Err(CommonError::CurveMismatch {
+                expected: SLIP10Curve::Secp256k1,
+                found: SLIP10Curve::Curve25519,
+            })
+        ));
+    }
+
+    #[test]
+    fn try_into_secp256k1() {
+        let sut = derive("m/44H/1022H/0H/0/0");
+        assert!(Secp256k1PrivateKey::try_from(sut.clone()).is_ok());
+        assert!(matches!(
+            Ed25519PrivateKey::try_from(sut),
+            Err(CommonError::CurveMismatch {
+                expected: SLIP10Curve::Curve25519,
+                found: SLIP10Curve::Secp256k1,
+            })
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::prelude::*;
+
+/// A public key on either of the curves keys are derived on.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, EnumAsInner, MoreDebug)]
+pub enum PublicKey {
+    #[debug("{:?}", _0)]
+    Ed25519(Ed25519PublicKey),
+    #[debug("{:?}", _0)]
+    Secp256k1(Secp256k1PublicKey),
+}
+
+impl PublicKey {
+    /// Parses the 32 bytes of an Ed25519 key or the 33 bytes of a compressed
+    /// Secp256k1 key, as dictated by `curve`.
+    pub fn from_bytes_on_curve(curve: SLIP10Curve, bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        match curve {
+            SLIP10Curve::Curve25519 => bytes
+                .try_into()
+                .map_err(|_| CommonError::InvalidEd25519PublicKey)
+                .and_then(Ed25519PublicKey::from_bytes)
+                .map(Self::Ed25519),
+            SLIP10Curve::Secp256k1 => bytes
+                .try_into()
+                .map_err(|_| CommonError::InvalidSecp256k1PublicKey)
+                .and_then(Secp256k1PublicKey::from_bytes)
+                .map(Self::Secp256k1),
+        }
+    }
+
+    /// The 32 bytes of an Ed25519 key or the 33 bytes of a compressed
+    /// Secp256k1 key.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Ed25519(key) => key.to_bytes().to_vec(),
+            Self::Secp256k1(key) => key.to_bytes().to_vec(),
+        }
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+
+    /// Whether `signature` is a valid signature of `message` by this key,
+    /// always `false` for a signature on the other curve.
+    pub fn verify(&self, signature: &Signature, message: impl AsRef<[u8]>) -> bool {
+        match (self, signature) {
+            (Self::Ed25519(key), Signature::Ed25519(signature)) => key.verify(signature, message),
+            (Self::Secp256k1(key), Signature::Secp256k1(signature)) => {
+                key.verify(signature, message)
+            }
+            _ => false,
+        }
+    }
+
+    /// The virtual address of the account controlled by this key on
+    /// `network_id`.
+    pub fn to_account_address(&self, network_id: NetworkID) -> AccountAddress {
+        AccountAddress::new(*self, network_id)
+    }
+
+    /// The virtual address of the identity controlled by this key on
+    /// `network_id`.
+    pub fn to_identity_address(&self, network_id: NetworkID) -> IdentityAddress {
+        IdentityAddress::new(*self, network_id)
+    }
+}
+
+impl HasSLIP10Curve for PublicKey {
+    fn curve(&self) -> SLIP10Curve {
+        match self {
+            Self::Ed25519(_) => SLIP10Curve::Curve25519,
+            Self::Secp256k1(_) => SLIP10Curve::Secp256k1,
+        }
+    }
+}
+
+impl From<Ed25519PublicKey> for PublicKey {
+    fn from(key: Ed25519PublicKey) -> Self {
+        Self::Ed25519(key)
+    }
+}
+
+impl From<Secp256k1PublicKey> for PublicKey {
+    fn from(key: Secp256k1PublicKey) -> Self {
+        Self::Secp256k1(key)
+    }
+}
+
+impl TryFrom<PublicKey> for Ed25519PublicKey {
+    type Error = CommonError;
+    fn try_from(key: PublicKey) -> Result<Self> {
+        let found = key.curve();
+        key.into_ed25519().map_err(|_| CommonError::CurveMismatch {
+            expected: SLIP10Curve::Curve25519,
+            found,
+        })
+    }
+}
+
+impl TryFrom<PublicKey> for Secp256k1PublicKey {
+    type Error = CommonError;
+    fn try_from(key: PublicKey) -> Result<Self> {
+        let found = key.curve();
+        key.into_secp256k1()
+            .map_err(|_| CommonError::CurveMismatch {
+                expected: SLIP10Curve::Secp256k1,
+                found,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = PublicKey;
+
+    fn derive(path: &str) -> Sut {
+        BIP39Seed::sample()
+            .derive_private_key(&DerivationPath::from_str(path).unwrap())
+            .unwrap()
+            .public_key()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(derive("m/44H/1022H/0H/0/0"), derive("m/44H/1022H/0H/0/0"));
+        assert_ne!(derive("m/44H/1022H/0H/0/0"), derive("m/44H/1022H/0H/0/1"));
+    }
+
+    #[test]
+    fn bytes_len() {
+        assert_eq!(derive("m/44H/1022H/12H/525H/1460H/0H").to_bytes().len(), 32);
+        assert_eq!(derive("m/44H/1022H/0H/0/0").to_bytes().len(), 33);
+    }
+
+    #[test]
+    fn debug_is_hex() {
+        let sut = derive("m/44H/1022H/12H/525H/1460H/0H");
+        assert_eq!(format!("{:?}", sut), sut.to_hex());
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(
+            derive("m/44H/1022H/12H/525H/1460H/0H").curve(),
+            SLIP10Curve::Curve25519
+        );
+        assert_eq!(derive("m/44H/1022H/0H/0/0").curve(), SLIP10Curve::Secp256k1);
+    }
+
+    #[test]
+    fn from_bytes_on_curve_roundtrip() {
+        for path in ["m/44H/1022H/12H/525H/1460H/0H", "m/44H/1022H/0H/0/0"] {
+            let sut = derive(path);
+            assert_eq!(
+                Sut::from_bytes_on_curve(sut.curve(), sut.to_bytes()).unwrap(),
+                sut
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes_on_wrong_curve_is_err() {
+        assert_eq!(
+            Sut::from_bytes_on_curve(
+                SLIP10Curve::Curve25519,
+                derive("m/44H/1022H/0H/0/0").to_bytes()
+            ),
+            Err(CommonError::InvalidEd25519PublicKey)
+        );
+        assert_eq!(
+            Sut::from_bytes_on_curve(
+                SLIP10Curve::Secp256k1,
+                derive("m/44H/1022H/12H/525H/1460H/0H").to_bytes()
+            ),
+            Err(CommonError::InvalidSecp256k1PublicKey)
+        );
+    }
+
+    #[test]
+    fn try_into_mismatched_curve_is_err() {
+        let sut = derive("m/44H/1022H/0H/0/0");
+        assert!(Secp256k1PublicKey::try_from(sut).is_ok());
+        assert!(matches!(
+            Ed25519PublicKey::try_from(sut),
+            Err(CommonError::CurveMismatch {
+                expected: SLIP10Curve::Curve25519,
+                found: SLIP10Curve::Secp256k1,
+            })
+        ));
+    }
+}
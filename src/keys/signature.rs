@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+/// A signature by a key on either of the curves keys are derived on, see
+/// `PrivateKey::sign`.
+#[derive(Clone, PartialEq, Eq, Hash, EnumAsInner, MoreDebug)]
+pub enum Signature {
+    #[debug("{:?}", _0)]
+    Ed25519(Ed25519Signature),
+    #[debug("{:?}", _0)]
+    Secp256k1(Secp256k1Signature),
+}
+
+impl HasSLIP10Curve for Signature {
+    fn curve(&self) -> SLIP10Curve {
+        match self {
+            Self::Ed25519(_) => SLIP10Curve::Curve25519,
+            Self::Secp256k1(_) => SLIP10Curve::Secp256k1,
+        }
+    }
+}
+
+impl From<Ed25519Signature> for Signature {
+    fn from(signature: Ed25519Signature) -> Self {
+        Self::Ed25519(signature)
+    }
+}
+
+impl From<Secp256k1Signature> for Signature {
+    fn from(signature: Secp256k1Signature) -> Self {
+        Self::Secp256k1(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derive(path: &str) -> PrivateKey {
+        BIP39Seed::sample()
+            .derive_private_key(&DerivationPath::from_str(path).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn sign_and_verify_on_both_curves() {
+        for path in ["m/44H/1022H/12H/525H/1460H/0H", "m/44H/1022H/0H/0/0"] {
+            let private_key = derive(path);
+            let signature = private_key.sign("Hello Radix");
+            assert_eq!(signature.curve(), private_key.curve());
+            assert!(private_key.public_key().verify(&signature, "Hello Radix"));
+            assert!(!private_key.public_key().verify(&signature, "Hello Olympia"));
+        }
+    }
+
+    #[test]
+    fn verify_signature_on_other_curve_is_false() {
+        let ed25519 = derive("m/44H/1022H/12H/525H/1460H/0H");
+        let secp256k1 = derive("m/44H/1022H/0H/0/0");
+        assert!(!ed25519
+            .public_key()
+            .verify(&secp256k1.sign("Hello Radix"), "Hello Radix"));
+        assert!(!secp256k1
+            .public_key()
+            .verify(&ed25519.sign("Hello Radix"), "Hello Radix"));
+    }
+}
@@ -1,8 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod address;
+mod factor_sources;
+#[cfg(feature = "std")]
+mod keys;
+#[cfg(feature = "std")]
+mod migration;
 mod new_types;
 mod paths;
 mod unchanged;
 
 pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use crate::address::*;
+    pub use crate::factor_sources::*;
+    #[cfg(feature = "std")]
+    pub use crate::keys::*;
+    #[cfg(feature = "std")]
+    pub use crate::migration::*;
     pub use crate::new_types::*;
     pub use crate::paths::*;
     pub use crate::unchanged::*;
@@ -11,10 +29,28 @@ pub mod prelude {
     pub(crate) use enum_as_inner::EnumAsInner;
     pub(crate) use serde_with::{DeserializeFromStr, SerializeDisplay};
 
-    pub(crate) use std::str::FromStr;
+    pub(crate) use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    pub(crate) use core::str::FromStr;
 
     #[cfg(test)]
     pub(crate) use std::collections::HashSet;
 }
 
 pub use prelude::*;
+
+/// Not public API, only public so that the path literal macros, e.g.
+/// `hdpath!`, can call these from other crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::paths::hd_path_literal::{
+        bip44_like_path_literal, cap26_account_path_literal, cap26_identity_path_literal,
+        hd_path_literal, hd_path_literal_len,
+    };
+}
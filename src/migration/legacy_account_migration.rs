@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// A legacy Olympia account, the Babylon account its funds have been in
+/// since the Olympia to Babylon network migration, and the fresh Babylon
+/// account they are moved to, all controlled by the same mnemonic.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyAccountMigration {
+    /// The path of the Secp256k1 key controlling the Olympia account.
+    pub legacy_path: BIP44LikePath,
+    pub legacy_address: LegacyOlympiaAccountAddress,
+    /// The Mainnet account of the Secp256k1 key of the Olympia account,
+    /// which holds its funds, see
+    /// `LegacyOlympiaAccountAddress::to_babylon_account_address`.
+    pub legacy_babylon_address: AccountAddress,
+    /// The freshly allocated path of the Ed25519 key controlling the
+    /// Babylon account.
+    pub babylon_path: CAP26AccountPath,
+    pub babylon_address: AccountAddress,
+}
+
+impl LegacyAccountMigration {
+    /// Derives the legacy account at `legacy_path` and the Babylon account at
+    /// `babylon_path` from `seed`.
+    pub fn derive(
+        seed: &BIP39Seed,
+        legacy_path: BIP44LikePath,
+        babylon_path: CAP26AccountPath,
+    ) -> Result<Self> {
+        let legacy_address = seed.derive_legacy_olympia_account_address(&legacy_path)?;
+        Ok(Self {
+            legacy_babylon_address: legacy_address.to_babylon_account_address(),
+            legacy_address,
+            babylon_address: seed.derive_account_address(&babylon_path)?,
+            legacy_path,
+            babylon_path,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = LegacyAccountMigration;
+
+    #[test]
+    fn derive() {
+        let seed = BIP39Seed::sample();
+        let sut = Sut::derive(
+            &seed,
+            BIP44LikePath::sample(),
+            CAP26AccountPath::sample_other(),
+        )
+        .unwrap();
+        assert_eq!(
+            sut.legacy_address.to_string(),
+            "rdx1qspmctkg7dngep54w7lkdda537x7u4acxwgk4fcfvmay55pfkcamrrc0lcarp"
+        );
+        assert_eq!(
+            sut.legacy_babylon_address.to_string(),
+            "account_rdx168cucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2k4jrhtk"
+        );
+        assert_eq!(
+            sut.babylon_address,
+            seed.derive_account_address(&CAP26AccountPath::sample_other())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_babylon_path_of_wrong_key_kind_is_err() {
+        let babylon_path = CAP26AccountPath::new(
+            NetworkID::Mainnet,
+            CAP26KeyKind::AuthenticationSigning,
+            Hardened::from_local_key_space_unsecurified(0u32).unwrap(),
+        );
+        assert!(matches!(
+            Sut::derive(&BIP39Seed::sample(), BIP44LikePath::sample(), babylon_path),
+            Err(CommonError::WrongKeyKind { .. })
+        ));
+    }
+}
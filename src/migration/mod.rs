@@ -0,0 +1,5 @@
+mod legacy_account_migration;
+mod olympia_migration_plan;
+
+pub use legacy_account_migration::*;
+pub use olympia_migration_plan::*;
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+
+/// Which fresh Babylon account the funds of each legacy Olympia account of a
+/// mnemonic are moved to, all fresh accounts on the same network.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OlympiaMigrationPlan {
+    pub network_id: NetworkID,
+    pub migrations: Vec<LegacyAccountMigration>,
+}
+
+impl OlympiaMigrationPlan {
+    /// Derives the legacy account at each of `legacy_indices` and pairs them,
+    /// in order, with Babylon accounts on `network_id` at consecutive
+    /// indices starting at `first_babylon_index`.
+    ///
+    /// Fails if the Babylon indices would overflow or leave the key space of
+    /// `first_babylon_index`.
+    pub fn scan(
+        seed: &BIP39Seed,
+        legacy_indices: impl IntoIterator<Item = HDPathComponent>,
+        network_id: NetworkID,
+        first_babylon_index: Hardened,
+    ) -> Result<Self> {
+        let migrations = legacy_indices
+            .into_iter()
+            .enumerate()
+            .map(|(offset, legacy_index)| {
+                let offset = u32::try_from(offset).map_err(|_| CommonError::Overflow)?;
+                let babylon_path = CAP26AccountPath::new(
+                    network_id,
+                    CAP26KeyKind::TransactionSigning,
+                    first_babylon_index.checked_add_n_to_global(offset)?,
+                );
+                LegacyAccountMigration::derive(seed, BIP44LikePath::new(legacy_index), babylon_path)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            network_id,
+            migrations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = OlympiaMigrationPlan;
+
+    fn unhardened(index: u32) -> HDPathComponent {
+        HDPathComponent::from_local_key_space(index, KeySpace::Unsecurified { is_hardened: false })
+            .unwrap()
+    }
+
+    fn hardened(index: u32) -> Hardened {
+        Hardened::from_local_key_space_unsecurified(index).unwrap()
+    }
+
+    #[test]
+    fn scan() {
+        let seed = BIP39Seed::sample();
+        let sut = Sut::scan(
+            &seed,
+            [unhardened(0), unhardened(1)],
+            NetworkID::Stokenet,
+            hardened(5),
+        )
+        .unwrap();
+        assert_eq!(sut.network_id, NetworkID::Stokenet);
+        assert_eq!(
+            sut.migrations
+                .iter()
+                .map(|m| (
+                    m.legacy_path.to_string(),
+                    m.legacy_address.to_string(),
+                    m.babylon_path.to_string()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "m/44H/1022H/0H/0/0",
+                    "rdx1qspmctkg7dngep54w7lkdda537x7u4acxwgk4fcfvmay55pfkcamrrc0lcarp",
+                    "m/44H/1022H/2H/525H/1460H/5H"
+                ),
+                (
+                    "m/44H/1022H/0H/0/1",
+                    "rdx1qspu3f49wy944waqjdquysuzmcez9yf3yr0w2zzw3p6jn0uzruuh8cs4tyy8l",
+                    "m/44H/1022H/2H/525H/1460H/6H"
+                ),
+            ]
+            .map(|(a, b, c)| (a.to_owned(), b.to_owned(), c.to_owned()))
+        );
+        for migration in &sut.migrations {
+            assert_eq!(
+                migration.babylon_address,
+                seed.derive_account_address(&migration.babylon_path)
+                    .unwrap()
+            );
+            assert_eq!(migration.babylon_address.network_id(), NetworkID::Stokenet);
+        }
+    }
+
+    #[test]
+    fn scan_no_indices_is_empty() {
+        let sut = Sut::scan(&BIP39Seed::sample(), [], NetworkID::Mainnet, hardened(0)).unwrap();
+        assert!(sut.migrations.is_empty());
+    }
+
+    #[test]
+    fn scan_babylon_index_leaving_key_space_is_err() {
+        let last = hardened(U30::MAX);
+        assert!(Sut::scan(
+            &BIP39Seed::sample(),
+            [unhardened(0)],
+            NetworkID::Mainnet,
+            last
+        )
+        .is_ok());
+        assert_eq!(
+            Sut::scan(
+                &BIP39Seed::sample(),
+                [unhardened(0), unhardened(1)],
+                NetworkID::Mainnet,
+                last
+            ),
+            Err(CommonError::CannotAddMoreToIndexSinceItWouldChangeKeySpace)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::scan(
+            &BIP39Seed::sample(),
+            [unhardened(0)],
+            NetworkID::Kisharnet,
+            hardened(0),
+        )
+        .unwrap();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!({
+                "networkId": 12,
+                "migrations": [{
+                    "legacyPath": "m/44H/1022H/0H/0/0",
+                    "legacyAddress": "rdx1qspmctkg7dngep54w7lkdda537x7u4acxwgk4fcfvmay55pfkcamrrc0lcarp",
+                    "legacyBabylonAddress": "account_rdx168cucf0r7h07hhzezrz8uem7lh0xxhgvrlv8htvqwjdxfn2k4jrhtk",
+                    "babylonPath": "m/44H/1022H/12H/525H/1460H/0H",
+                    "babylonAddress": "account_tdx_c_129wjagjzxltd0clr3q4z7hqpw5cc7weh9trs4e9k3zfwqpj6aw829s"
+                }]
+            }),
+        );
+    }
+}
@@ -2,6 +2,11 @@ use crate::prelude::*;
 
 /// A component for a BIP32 hd path, mappable into a `u32`. Retains information about the creating context, if this component is securified or not. And if it is not securified it is hardened or not.
 ///
+/// Stored as its index in the global key space, a single `u32`, from which
+/// the key space is derived, so that mapping to the global key space is free
+/// and a component is no bigger than the index it represents. Since every
+/// `u32` is a valid component there is no niche left, thus
+/// `Option<HDPathComponent>` is 8 bytes.
 ///
 /// ```ignore
 /// [ <<------- UNHARDENED ------->> | <<-------- HARDENED --------->> ]
@@ -18,18 +23,20 @@ use crate::prelude::*;
 ///
 /// assert_eq!(
 ///     HDPathComponent::from_global_key_space(0).unwrap(),
-///     HDPathComponent::Unsecurified(Unsecurified::Unhardened(Unhardened::ZERO))
+///     HDPathComponent::from(Unhardened::ZERO)
 /// );
 ///
 /// assert_eq!(
 ///     HDPathComponent::from_global_key_space(1 + GLOBAL_OFFSET_HARDENED).unwrap(),
-///     HDPathComponent::Unsecurified(Unsecurified::Hardened(UnsecurifiedHardened::ONE))
+///     HDPathComponent::from(UnsecurifiedHardened::ONE)
 /// );
 ///
 /// assert_eq!(
 ///     HDPathComponent::from_global_key_space(2 + GLOBAL_OFFSET_HARDENED_SECURIFIED).unwrap(),
-///     HDPathComponent::Securified(SecurifiedU30::TWO)
+///     HDPathComponent::from(SecurifiedU30::TWO)
 /// );
+///
+/// assert_eq!(std::mem::size_of::<HDPathComponent>(), 4);
 /// ```
 #[derive(
     Clone,
@@ -39,88 +46,142 @@ use crate::prelude::*;
     PartialOrd,
     Ord,
     Hash,
-    EnumAsInner,
     Display,
     MoreDebug,
     DeserializeFromStr,
     SerializeDisplay,
 )]
-pub enum HDPathComponent {
-    #[display("{_0}")]
-    #[debug("{:?}", _0)]
-    Unsecurified(Unsecurified),
-
-    #[display("{_0}")]
-    #[debug("{:?}", _0)]
-    Securified(SecurifiedU30),
-}
+#[display("{}", self.to_bip32_string())]
+#[debug("{}", self.to_bip32_string_debug())]
+pub struct HDPathComponent(u32);
 
 impl AddViaGlobalKeySpace for HDPathComponent {}
 
 impl HasSampleValues for HDPathComponent {
     fn sample() -> Self {
-        Self::Unsecurified(Unsecurified::sample())
+        Self::from(Unsecurified::sample())
     }
 
     fn sample_other() -> Self {
-        Self::Securified(SecurifiedU30::sample_other())
+        Self::from(SecurifiedU30::sample_other())
     }
 }
 
 impl IsInLocalKeySpace for HDPathComponent {
     fn key_space(&self) -> KeySpace {
-        match self {
-            Self::Unsecurified(u) => u.key_space(),
-            Self::Securified(s) => s.key_space(),
+        if self.is_securified() {
+            KeySpace::Securified
+        } else {
+            KeySpace::Unsecurified {
+                is_hardened: self.is_hardened(),
+            }
         }
     }
     fn index_in_local_key_space(&self) -> U31 {
-        match self {
-            Self::Unsecurified(u) => u.index_in_local_key_space(),
-            Self::Securified(s) => s.index_in_local_key_space(),
-        }
+        let offset = match self.key_space() {
+            KeySpace::Securified => GLOBAL_OFFSET_HARDENED_SECURIFIED,
+            KeySpace::Unsecurified { is_hardened: true } => GLOBAL_OFFSET_HARDENED,
+            KeySpace::Unsecurified { is_hardened: false } => 0,
+        };
+        U31::checked(self.0 - offset).expect("Local index is always less than 2^31")
     }
 }
 
 impl FromGlobalKeySpace for HDPathComponent {
     fn from_global_key_space(value: u32) -> Result<Self> {
-        SecurifiedU30::from_global_key_space(value)
-            .map(Self::Securified)
-            .or(Unsecurified::from_global_key_space(value).map(Self::Unsecurified))
+        Ok(Self(value))
     }
 }
 
 impl From<Hardened> for HDPathComponent {
     fn from(value: Hardened) -> Self {
-        match value {
-            Hardened::Unsecurified(u) => HDPathComponent::Unsecurified(Unsecurified::from(u)),
-            Hardened::Securified(s) => HDPathComponent::Securified(s),
-        }
+        Self(value.map_to_global_key_space())
+    }
+}
+
+impl From<Unhardened> for HDPathComponent {
+    fn from(value: Unhardened) -> Self {
+        Self(value.map_to_global_key_space())
+    }
+}
+
+impl From<UnsecurifiedHardened> for HDPathComponent {
+    fn from(value: UnsecurifiedHardened) -> Self {
+        Self(value.map_to_global_key_space())
+    }
+}
+
+impl From<Unsecurified> for HDPathComponent {
+    fn from(value: Unsecurified) -> Self {
+        Self(value.map_to_global_key_space())
+    }
+}
+
+impl From<SecurifiedU30> for HDPathComponent {
+    fn from(value: SecurifiedU30) -> Self {
+        Self(value.map_to_global_key_space())
     }
 }
 
 impl HDPathComponent {
-    fn securified(value: impl Into<SecurifiedU30>) -> Self {
-        Self::Securified(value.into())
+    /// Const version of `from_global_key_space`, which cannot fail since
+    /// every `u32` is in some key space.
+    pub(crate) const fn from_global(value: u32) -> Self {
+        Self(value)
     }
 
     pub fn unsecurified_hardened(local_index: u32) -> Result<Self> {
         Self::from_local_key_space(local_index, KeySpace::Unsecurified { is_hardened: true })
     }
+
+    /// Parses `s` as a raw index in the global key space, e.g. `2147483692`
+    /// for `44H`, or as a securified index written as its hardened value,
+    /// e.g. `1073741831'` or `1073741831h` for `7S`.
+    fn from_bip32_string_global(s: &str) -> Option<Self> {
+        let (digits, offset) = match s
+            .strip_suffix(UnsecurifiedHardened::NON_CANONICAL_SUFFIXES)
+            .or(s.strip_suffix(UNSECURIFIED_HARDENED_LOWERCASE_SUFFIX))
+        {
+            Some(digits) => (digits, GLOBAL_OFFSET_HARDENED),
+            None => (s, 0),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let global = digits.parse::<u32>().ok()?.checked_add(offset)?;
+        Self::from_global_key_space(global).ok()
+    }
 }
 
 impl HDPathComponent {
+    pub fn is_securified(&self) -> bool {
+        self.0 >= GLOBAL_OFFSET_HARDENED_SECURIFIED
+    }
+    pub fn is_unsecurified(&self) -> bool {
+        !self.is_securified()
+    }
     pub fn is_unhardened(&self) -> bool {
-        match self {
-            Self::Unsecurified(u) => u.is_unhardened(),
-            Self::Securified(_) => false,
-        }
+        self.0 < GLOBAL_OFFSET_HARDENED
     }
     pub fn is_hardened(&self) -> bool {
-        match self {
-            Self::Unsecurified(u) => u.is_hardened(),
-            Self::Securified(_) => true,
+        !self.is_unhardened()
+    }
+
+    pub fn as_securified(&self) -> Option<SecurifiedU30> {
+        self.into_securified().ok()
+    }
+    pub fn as_unsecurified(&self) -> Option<Unsecurified> {
+        self.into_unsecurified().ok()
+    }
+
+    pub fn into_securified(self) -> Result<SecurifiedU30, Self> {
+        SecurifiedU30::from_global_key_space(self.0).map_err(|_| self)
+    }
+    pub fn into_unsecurified(self) -> Result<Unsecurified, Self> {
+        if self.is_securified() {
+            return Err(self);
         }
+        Unsecurified::from_global_key_space(self.0).map_err(|_| self)
     }
 }
 
@@ -128,8 +189,9 @@ impl FromBIP32Str for HDPathComponent {
     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
         let s = s.as_ref();
         SecurifiedU30::from_bip32_string(s)
-            .map(Self::securified)
-            .or(Unsecurified::from_bip32_string(s).map(Self::Unsecurified))
+            .map(Self::from)
+            .or(Unsecurified::from_bip32_string(s).map(Self::from))
+            .or_else(|e| Self::from_bip32_string_global(s).ok_or(e))
     }
 }
 
@@ -140,23 +202,45 @@ impl FromStr for HDPathComponent {
     }
 }
 
+impl ToBIP32Str for HDPathComponent {
+    fn to_bip32_string(&self) -> String {
+        let suffix = match self.key_space() {
+            KeySpace::Securified => SecurifiedU30::CANONICAL_SUFFIX,
+            KeySpace::Unsecurified { is_hardened: true } => UnsecurifiedHardened::CANONICAL_SUFFIX,
+            KeySpace::Unsecurified { is_hardened: false } => Unhardened::CANONICAL_SUFFIX,
+        };
+        format!("{}{}", u32::from(self.index_in_local_key_space()), suffix)
+    }
+    fn to_bip32_string_debug(&self) -> String {
+        let suffix = match self.key_space() {
+            KeySpace::Securified => SecurifiedU30::NON_CANONICAL_SUFFIXES,
+            KeySpace::Unsecurified { is_hardened: true } => {
+                UnsecurifiedHardened::NON_CANONICAL_SUFFIXES
+            }
+            KeySpace::Unsecurified { is_hardened: false } => Unhardened::NON_CANONICAL_SUFFIXES,
+        };
+        format!("{}{}", u32::from(self.index_in_local_key_space()), suffix)
+    }
+}
+
+impl ToBIP32StrWithFormat for HDPathComponent {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        format.format_component(self)
+    }
+}
+
 impl IsMappableToGlobalKeySpace for HDPathComponent {
     fn map_to_global_key_space(&self) -> u32 {
-        match self {
-            HDPathComponent::Unsecurified(u) => u.map_to_global_key_space(),
-            HDPathComponent::Securified(s) => s.map_to_global_key_space(),
-        }
+        self.0
     }
 }
 
 impl HDPathComponent {
     pub fn from_local_key_space(value: u32, key_space: KeySpace) -> Result<Self> {
         match key_space {
-            KeySpace::Securified => {
-                SecurifiedU30::from_local_key_space(value).map(Self::Securified)
-            }
+            KeySpace::Securified => SecurifiedU30::from_local_key_space(value).map(Self::from),
             KeySpace::Unsecurified { is_hardened } => {
-                Unsecurified::from_local_key_space(value, is_hardened).map(Self::Unsecurified)
+                Unsecurified::from_local_key_space(value, is_hardened).map(Self::from)
             }
         }
     }
@@ -220,9 +304,84 @@ mod tests {
         )
     }
 
+    #[test]
+    fn size() {
+        assert_eq!(std::mem::size_of::<Sut>(), std::mem::size_of::<u32>());
+        assert_eq!(std::mem::align_of::<Sut>(), std::mem::align_of::<u32>());
+    }
+
+    #[test]
+    fn ord_is_global_ord() {
+        let globals = [
+            0,
+            U31_MAX,
+            GLOBAL_OFFSET_HARDENED,
+            GLOBAL_OFFSET_HARDENED_SECURIFIED - 1,
+            GLOBAL_OFFSET_HARDENED_SECURIFIED,
+            u32::MAX,
+        ];
+        for (a, b) in globals.iter().zip(globals.iter().skip(1)) {
+            assert!(Sut::from_global(*a) < Sut::from_global(*b));
+        }
+    }
+
+    #[test]
+    fn key_space_boundaries() {
+        assert_eq!(
+            Sut::from_global(U31_MAX).key_space(),
+            KeySpace::Unsecurified { is_hardened: false }
+        );
+        assert_eq!(
+            Sut::from_global(GLOBAL_OFFSET_HARDENED).key_space(),
+            KeySpace::Unsecurified { is_hardened: true }
+        );
+        assert_eq!(
+            Sut::from_global(GLOBAL_OFFSET_HARDENED_SECURIFIED - 1).key_space(),
+            KeySpace::Unsecurified { is_hardened: true }
+        );
+        assert_eq!(
+            Sut::from_global(GLOBAL_OFFSET_HARDENED_SECURIFIED).key_space(),
+            KeySpace::Securified
+        );
+    }
+
+    #[test]
+    fn as_securified() {
+        let sec = SecurifiedU30::sample();
+        assert_eq!(Sut::from(sec).as_securified(), Some(sec));
+        assert_eq!(Sut::sample().as_securified(), None);
+    }
+
+    #[test]
+    fn as_unsecurified() {
+        let unsec = Unsecurified::sample_other();
+        assert_eq!(Sut::from(unsec).as_unsecurified(), Some(unsec));
+        assert_eq!(Sut::from(SecurifiedU30::sample()).as_unsecurified(), None);
+    }
+
+    #[test]
+    fn into_securified_err_is_self() {
+        assert_eq!(Sut::sample().into_securified(), Err(Sut::sample()));
+        assert_eq!(
+            Sut::sample_other().into_unsecurified(),
+            Err(Sut::sample_other())
+        );
+    }
+
+    #[test]
+    fn display_and_debug_of_each_key_space() {
+        let sut = |s: &str| s.parse::<Sut>().unwrap();
+        assert_eq!(sut("5").to_string(), "5");
+        assert_eq!(sut("5H").to_string(), "5H");
+        assert_eq!(sut("5S").to_string(), "5S");
+        assert_eq!(format!("{:?}", sut("5")), "5");
+        assert_eq!(format!("{:?}", sut("5H")), "5'");
+        assert_eq!(format!("{:?}", sut("5S")), "5^");
+    }
+
     #[test]
     fn key_space() {
-        let sut = Sut::Securified(SecurifiedU30::sample());
+        let sut = Sut::from(SecurifiedU30::sample());
         assert!(sut.key_space().is_securified())
     }
 
@@ -269,13 +428,38 @@ mod tests {
     fn from_hardened() {
         let sec = SecurifiedU30::sample();
         let hardened = Hardened::Securified(sec);
-        assert_eq!(Sut::from(hardened), Sut::Securified(sec));
+        assert_eq!(Sut::from(hardened), Sut::from(sec));
+    }
+
+    #[test]
+    fn from_global_const() {
+        for global in [
+            0,
+            U31_MAX,
+            GLOBAL_OFFSET_HARDENED,
+            GLOBAL_OFFSET_HARDENED_SECURIFIED - 1,
+            GLOBAL_OFFSET_HARDENED_SECURIFIED,
+            u32::MAX,
+        ] {
+            assert_eq!(
+                Sut::from_global(global),
+                Sut::from_global_key_space(global).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn from_unhardened() {
+        assert_eq!(
+            Sut::from(Unhardened::sample()),
+            Sut::from_global_key_space(237).unwrap()
+        );
     }
 
     #[test]
     fn map_to_global_securified() {
         let sec = SecurifiedU30::sample();
-        let sut = Sut::Securified(sec);
+        let sut = Sut::from(sec);
         assert_eq!(
             sut.map_to_global_key_space(),
             30 + GLOBAL_OFFSET_HARDENED_SECURIFIED
@@ -286,7 +470,7 @@ mod tests {
     fn from_local_key_space_securified() {
         assert_eq!(
             Sut::from_local_key_space(42, KeySpace::Securified).unwrap(),
-            Sut::securified(U30::new(42))
+            Sut::from(SecurifiedU30::from(U30::new(42)))
         )
     }
 
@@ -359,6 +543,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_str_lowercase_h() {
+        assert_eq!(
+            "44h".parse::<Sut>().unwrap(),
+            Sut::from_global_key_space(44 + GLOBAL_OFFSET_HARDENED).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_global_hardened() {
+        assert_eq!(
+            "2147483692".parse::<Sut>().unwrap(),
+            "44H".parse::<Sut>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_global_securified() {
+        assert_eq!(
+            "3221225479".parse::<Sut>().unwrap(),
+            "7S".parse::<Sut>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_global_max() {
+        assert_eq!(
+            "4294967295".parse::<Sut>().unwrap(),
+            Sut::from(SecurifiedU30::from_local_key_space(U30_MAX).unwrap())
+        );
+    }
+
+    #[test]
+    fn from_str_securified_as_hardened() {
+        let expected = "7S".parse::<Sut>().unwrap();
+        assert_eq!("1073741831'".parse::<Sut>().unwrap(), expected);
+        assert_eq!("1073741831h".parse::<Sut>().unwrap(), expected);
+    }
+
+    #[test]
+    fn from_str_securified_as_canonical_hardened_is_err() {
+        assert!("1073741831H".parse::<Sut>().is_err());
+    }
+
+    #[test]
+    fn from_str_global_round_trips_to_canonical() {
+        assert_eq!("2147483692".parse::<Sut>().unwrap().to_string(), "44H");
+        assert_eq!("44h".parse::<Sut>().unwrap().to_string(), "44H");
+    }
+
+    #[test]
+    fn from_str_global_invalid() {
+        assert!("4294967296".parse::<Sut>().is_err());
+        assert!("+2147483692".parse::<Sut>().is_err());
+        assert!("2147483692h".parse::<Sut>().is_err());
+        assert!("h".parse::<Sut>().is_err());
+    }
+
     #[test]
     fn display_0() {
         assert_eq!(format!("{}", Sut::from_global_key_space(0).unwrap()), "0");
@@ -396,21 +638,21 @@ mod tests {
     fn from_global() {
         assert_eq!(
             Sut::from_global_key_space(1337).unwrap(),
-            Sut::Unsecurified(Unsecurified::Unhardened(
+            Sut::from(Unsecurified::Unhardened(
                 Unhardened::from_local_key_space(1337).unwrap()
             ))
         );
 
         assert_eq!(
             Sut::from_global_key_space(42 + GLOBAL_OFFSET_HARDENED).unwrap(),
-            Sut::Unsecurified(Unsecurified::Hardened(
+            Sut::from(Unsecurified::Hardened(
                 UnsecurifiedHardened::from_local_key_space(42).unwrap()
             ))
         );
 
         assert_eq!(
             Sut::from_global_key_space(237 + GLOBAL_OFFSET_HARDENED_SECURIFIED).unwrap(),
-            Sut::Securified(SecurifiedU30::from_local_key_space(237).unwrap())
+            Sut::from(SecurifiedU30::from_local_key_space(237).unwrap())
         );
     }
 
@@ -508,7 +750,7 @@ mod tests {
 
     #[test]
     fn add_one_unsecurified_unhardened_max_is_err() {
-        let sut = Sut::Unsecurified(Unsecurified::Unhardened(
+        let sut = Sut::from(Unsecurified::Unhardened(
             Unhardened::from_local_key_space(Unhardened::MAX_LOCAL).unwrap(),
         ));
         assert!(sut.checked_add_one_to_global().is_err());
@@ -517,7 +759,7 @@ mod tests {
     #[test]
     fn cannot_add_one_to_max_unsecurified_unhardened() {
         assert!(matches!(
-            Sut::Unsecurified(Unsecurified::Unhardened(
+            Sut::from(Unsecurified::Unhardened(
                 Unhardened::from_local_key_space(Unhardened::MAX_LOCAL).unwrap()
             ))
             .checked_add_n_to_global(1),
@@ -528,7 +770,7 @@ mod tests {
     #[test]
     fn cannot_add_one_to_max_unsecurified_hardened() {
         assert!(matches!(
-            Sut::Unsecurified(Unsecurified::Hardened(
+            Sut::from(Unsecurified::Hardened(
                 UnsecurifiedHardened::from_local_key_space(UnsecurifiedHardened::MAX_LOCAL)
                     .unwrap()
             ))
@@ -141,6 +141,15 @@ impl FromBIP32Str for Hardened {
     }
 }
 
+impl ToBIP32StrWithFormat for Hardened {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        match self {
+            Self::Unsecurified(u) => u.to_bip32_string_with_format(format),
+            Self::Securified(s) => s.to_bip32_string_with_format(format),
+        }
+    }
+}
+
 pub struct IsSecurified(pub bool);
 
 impl Hardened {
@@ -163,11 +172,9 @@ impl TryFrom<HDPathComponent> for Hardened {
     type Error = CommonError;
 
     fn try_from(value: HDPathComponent) -> Result<Self> {
-        match value {
-            HDPathComponent::Unsecurified(u) => {
-                UnsecurifiedHardened::try_from(u).map(Self::Unsecurified)
-            }
-            HDPathComponent::Securified(s) => Ok(Hardened::Securified(s)),
+        match value.into_unsecurified() {
+            Ok(u) => UnsecurifiedHardened::try_from(u).map(Self::Unsecurified),
+            Err(s) => SecurifiedU30::try_from(s).map(Self::Securified),
         }
     }
 }
@@ -455,7 +462,7 @@ mod tests {
     fn try_from_hd_path_component_securified() {
         let secu30 = SecurifiedU30::sample();
         let sut = Sut::Securified(secu30);
-        let from = HDPathComponent::Securified(secu30);
+        let from = HDPathComponent::from(secu30);
         assert_eq!(Sut::try_from(from).unwrap(), sut)
     }
 
@@ -61,6 +61,10 @@ use crate::prelude::*;
 pub struct SecurifiedU30(U30);
 
 impl SecurifiedU30 {
+    pub(crate) const fn new(value: U30) -> Self {
+        Self(value)
+    }
+
     pub const MAX_LOCAL: u32 = U30::MAX;
 
     /// `Self::from_local_key_space(0).unwrap()`
@@ -115,7 +119,7 @@ impl From<U30> for SecurifiedU30 {
 impl TryFrom<u32> for SecurifiedU30 {
     type Error = CommonError;
 
-    fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: u32) -> core::result::Result<Self, Self::Error> {
         U30::try_from(value).map(Self)
     }
 }
@@ -268,13 +272,13 @@ mod tests {
     #[test]
     fn try_from_hd_path_component_successful() {
         let sut = Sut::sample();
-        let from = HDPathComponent::Securified(sut);
+        let from = HDPathComponent::from(sut);
         assert_eq!(Sut::try_from(from).unwrap(), sut)
     }
 
     #[test]
     fn try_from_hd_path_component_fail() {
-        let from = HDPathComponent::Unsecurified(Unsecurified::sample());
+        let from = HDPathComponent::from(Unsecurified::sample());
         assert!(matches!(
             Sut::try_from(from),
             Err(CommonError::IndexUnsecurifiedExpectedSecurified)
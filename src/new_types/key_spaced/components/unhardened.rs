@@ -49,12 +49,6 @@ use crate::prelude::*;
 #[debug("{}", self.to_bip32_string_debug())]
 pub struct Unhardened(U31);
 
-impl Unhardened {
-    pub(crate) const fn new(value: U31) -> Self {
-        Self(value)
-    }
-}
-
 impl Unhardened {
     pub const MAX_LOCAL: u32 = U31::MAX;
 
@@ -112,7 +106,7 @@ impl From<U31> for Unhardened {
 impl TryFrom<u32> for Unhardened {
     type Error = CommonError;
 
-    fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: u32) -> core::result::Result<Self, Self::Error> {
         U31::try_from(value).map(Self)
     }
 }
@@ -140,6 +140,15 @@ impl FromStr for Unsecurified {
     }
 }
 
+impl ToBIP32StrWithFormat for Unsecurified {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        match self {
+            Self::Unhardened(u) => u.to_bip32_string_with_format(format),
+            Self::Hardened(h) => h.to_bip32_string_with_format(format),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -389,7 +398,7 @@ mod tests {
 
     #[test]
     fn try_from_hd_path_component_fail() {
-        let from = HDPathComponent::Securified(SecurifiedU30::sample());
+        let from = HDPathComponent::from(SecurifiedU30::sample());
 
         assert!(matches!(
             Sut::try_from(from),
@@ -400,7 +409,7 @@ mod tests {
     #[test]
     fn try_from_hd_path_component_success() {
         let sut = Unsecurified::sample();
-        let from = HDPathComponent::Unsecurified(sut);
+        let from = HDPathComponent::from(sut);
         assert_eq!(Sut::try_from(from).unwrap(), sut)
     }
 
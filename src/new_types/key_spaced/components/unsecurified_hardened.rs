@@ -76,11 +76,7 @@ impl HasSampleValues for UnsecurifiedHardened {
 }
 
 impl UnsecurifiedHardened {
-    /// # Safety
-    /// Unsafe, does not validate the value to be small enough.
-    ///
-    /// Only use this for tests and constants.
-    pub(crate) const unsafe fn new(value: U30) -> Self {
+    pub(crate) const fn new(value: U30) -> Self {
         Self(value)
     }
 }
@@ -97,16 +93,18 @@ impl From<U30> for UnsecurifiedHardened {
 impl TryFrom<u32> for UnsecurifiedHardened {
     type Error = CommonError;
 
-    fn try_from(value: u32) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: u32) -> core::result::Result<Self, Self::Error> {
         U30::try_from(value).map(Self)
     }
 }
 
 pub const UNSECURIFIED_HARDENED_CANONICAL_SUFFIX: &str = "H";
 pub const UNSECURIFIED_HARDENED_NON_CANONICAL_SUFFIX: &str = "'";
+pub const UNSECURIFIED_HARDENED_LOWERCASE_SUFFIX: &str = "h";
 impl IsPathComponentStringConvertible for UnsecurifiedHardened {
     const CANONICAL_SUFFIX: &'static str = UNSECURIFIED_HARDENED_CANONICAL_SUFFIX;
     const NON_CANONICAL_SUFFIXES: &'static str = UNSECURIFIED_HARDENED_NON_CANONICAL_SUFFIX;
+    const LENIENT_SUFFIXES: &'static [&'static str] = &[UNSECURIFIED_HARDENED_LOWERCASE_SUFFIX];
 }
 
 impl IsInLocalKeySpace for UnsecurifiedHardened {
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-pub trait AddViaDeref: std::ops::Deref<Target = u32> + TryFrom<u32, Error = CommonError> {
+pub trait AddViaDeref: core::ops::Deref<Target = u32> + TryFrom<u32, Error = CommonError> {
     fn checked_add_one(&self) -> Result<Self> {
         self.checked_add_n(1)
     }
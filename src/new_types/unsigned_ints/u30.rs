@@ -10,6 +10,16 @@ impl U30 {
         Self(value as u32)
     }
 
+    /// Const version of `TryFrom<u32>`, `None` if `value` is larger than
+    /// `Self::MAX`.
+    pub(crate) const fn checked(value: u32) -> Option<Self> {
+        if value <= Self::MAX {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
     pub const ZERO: Self = Self(0);
     pub const ONE: Self = Self(1);
     pub const TWO: Self = Self(2);
@@ -1,31 +1,238 @@
 use crate::prelude::*;
 
-#[derive(
-    Clone,
-    PartialEq,
-    Eq,
-    Hash,
-    EnumAsInner,
-    PartialOrd,
-    Ord,
-    // derive_more::Display,
-    derive_more::Debug,
-)]
-pub enum AccountPath {
-    // #[debug("{}", self.bip32_string())]
-    CAP26 { value: CAP26AccountPath },
-    // #[debug("{}", self.bip32_string())]
-    BIP44Like { value: BIP44LikePath },
-}
-
-// impl ToBip32String for AnyAccountPath {
-//     fn bip32_string(&self) -> String {
-//         todo!()
-//     }
-// }
-// impl FromBip32String for AnyAccountPath {
-//     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-//         todo!()
-//     }
-// }
-// impl_from_str_for__from_bip32_str!(AnyAccountPath);
+path_union!(
+    /// A derivation path of an account, either a Babylon `CAP26AccountPath`
+    /// or an Olympia `BIP44LikePath`.
+    AccountPath,
+    CAP26, CAP26AccountPath
+    BIP44Like, BIP44LikePath
+);
+
+impl AccountPath {
+    /// The network of the account, only known for `CAP26` paths, since
+    /// `BIP44Like` paths do not contain any network.
+    pub fn network_id(&self) -> Option<NetworkID> {
+        match self {
+            Self::CAP26 { value } => Some(value.network_id()),
+            Self::BIP44Like { .. } => None,
+        }
+    }
+}
+
+impl IsSecurityStateAware for AccountPath {
+    fn is_securified(&self) -> bool {
+        match self {
+            Self::CAP26 { value } => value.is_securified(),
+            Self::BIP44Like { value } => value.is_securified(),
+        }
+    }
+}
+
+impl From<CAP26AccountPath> for AccountPath {
+    fn from(path: CAP26AccountPath) -> Self {
+        Self::cap26(path)
+    }
+}
+
+impl From<BIP44LikePath> for AccountPath {
+    fn from(path: BIP44LikePath) -> Self {
+        Self::bip44like(path)
+    }
+}
+
+impl TryFrom<DerivationPath> for AccountPath {
+    type Error = CommonError;
+    fn try_from(path: DerivationPath) -> Result<Self> {
+        match path {
+            DerivationPath::Account { value } => Ok(Self::cap26(value)),
+            DerivationPath::BIP44Like { value } => Ok(Self::bip44like(value)),
+            DerivationPath::Identity { .. } => Err(CommonError::WrongEntityKind {
+                expected: CAP26EntityKind::Account,
+                found: CAP26EntityKind::Identity,
+            }),
+        }
+    }
+}
+
+impl From<AccountPath> for DerivationPath {
+    fn from(path: AccountPath) -> Self {
+        match path {
+            AccountPath::CAP26 { value } => Self::account(value),
+            AccountPath::BIP44Like { value } => Self::bip44like(value),
+        }
+    }
+}
+
+impl HasSampleValues for AccountPath {
+    fn sample() -> Self {
+        Self::cap26(CAP26AccountPath::sample())
+    }
+    fn sample_other() -> Self {
+        Self::bip44like(BIP44LikePath::sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = AccountPath;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Sut::sample()), "m/44H/1022H/1H/525H/1460H/0H");
+        assert_eq!(format!("{}", Sut::sample_other()), "m/44H/1022H/0H/0/0");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Sut::sample()),
+            "m/44'/1022'/1'/525'/1460'/0'"
+        );
+        assert_eq!(format!("{:?}", Sut::sample_other()), "m/44'/1022'/0'/0/0");
+    }
+
+    #[test]
+    fn string_roundtrip_cap26() {
+        let sut = Sut::sample();
+        assert_eq!(Sut::from_bip32_string(sut.to_bip32_string()).unwrap(), sut);
+    }
+
+    #[test]
+    fn string_roundtrip_bip44() {
+        let sut = Sut::sample_other();
+        assert_eq!(Sut::from_bip32_string(sut.to_bip32_string()).unwrap(), sut);
+    }
+
+    #[test]
+    fn from_str_identity_fails() {
+        let Err(CommonError::InvalidPathUnion(error)) =
+            Sut::from_str("m/44H/1022H/1H/618H/1460H/0H")
+        else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.union, "AccountPath");
+        assert_eq!(
+            error
+                .rejections
+                .iter()
+                .map(|r| r.variant.as_str())
+                .collect::<Vec<_>>(),
+            vec!["CAP26", "BIP44Like"]
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_cap26() {
+        let sut = Sut::sample();
+
+        assert_json_value_eq_after_roundtrip(&sut, json!("m/44H/1022H/1H/525H/1460H/0H"));
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn json_roundtrip_bip44() {
+        let sut = Sut::sample_other();
+
+        assert_json_value_eq_after_roundtrip(&sut, json!("m/44H/1022H/0H/0/0"));
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn json_roundtrip_mixed_list() {
+        let sut = vec![Sut::sample(), Sut::sample_other()];
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            json!(["m/44H/1022H/1H/525H/1460H/0H", "m/44H/1022H/0H/0/0"]),
+        );
+    }
+
+    #[test]
+    fn json_fails_for_invalid() {
+        assert_json_value_fails::<Sut>(json!(""));
+        assert_json_value_fails::<Sut>(json!("foobar"));
+        assert_json_value_fails::<Sut>(json!("m/44H/1022H/1H/618H/1460H/0H"));
+    }
+
+    #[test]
+    fn try_from_derivation_path_account() {
+        let value = CAP26AccountPath::sample();
+        assert_eq!(
+            Sut::try_from(DerivationPath::account(value.clone())).unwrap(),
+            Sut::cap26(value)
+        );
+    }
+
+    #[test]
+    fn try_from_derivation_path_bip44() {
+        let value = BIP44LikePath::sample();
+        assert_eq!(
+            Sut::try_from(DerivationPath::bip44like(value.clone())).unwrap(),
+            Sut::bip44like(value)
+        );
+    }
+
+    #[test]
+    fn try_from_derivation_path_identity_fails() {
+        assert_eq!(
+            Sut::try_from(DerivationPath::identity(CAP26IdentityPath::sample())),
+            Err(CommonError::WrongEntityKind {
+                expected: CAP26EntityKind::Account,
+                found: CAP26EntityKind::Identity,
+            })
+        );
+    }
+
+    #[test]
+    fn into_derivation_path_roundtrip() {
+        let sut = Sut::sample_other();
+        assert_eq!(
+            Sut::try_from(DerivationPath::from(sut.clone())).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn into_hd_path() {
+        assert_eq!(
+            HDPath::from(Sut::sample()),
+            CAP26AccountPath::sample().to_hd_path()
+        );
+        assert_eq!(
+            HDPath::from(Sut::sample_other()),
+            BIP44LikePath::sample().to_hd_path()
+        );
+    }
+
+    #[test]
+    fn network_id() {
+        assert_eq!(Sut::sample().network_id(), Some(NetworkID::Mainnet));
+        assert_eq!(Sut::sample_other().network_id(), None);
+    }
+
+    #[test]
+    fn is_securified() {
+        assert!(!Sut::sample().is_securified());
+        assert!(!Sut::sample_other().is_securified());
+        assert!(Sut::cap26(CAP26AccountPath::new(
+            NetworkID::Mainnet,
+            CAP26KeyKind::TransactionSigning,
+            Hardened::sample_other()
+        ))
+        .is_securified());
+    }
+}
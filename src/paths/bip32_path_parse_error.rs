@@ -0,0 +1,185 @@
+use crate::prelude::*;
+
+/// A range of bytes, `start..end`, into a string that was parsed.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, derive_more::Display)]
+#[display("{start}..{end}")]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// An empty span at `position`, used to point at something missing,
+    /// e.g. a component past the end of the input.
+    pub fn empty_at(position: usize) -> Self {
+        Self::new(position, position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A component of an `HDPath` that failed validation, identified by its
+/// index in the path, not yet tied to any input string.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InvalidHDPathComponent {
+    pub index: usize,
+    pub expected: String,
+    pub reason: CommonError,
+}
+
+impl InvalidHDPathComponent {
+    pub fn new(index: usize, expected: impl Into<String>, reason: CommonError) -> Self {
+        Self {
+            index,
+            expected: expected.into(),
+            reason,
+        }
+    }
+
+    /// Fails with `CommonError::InvalidLength` if `len`, the number of
+    /// components, is not `expected_len`, pointing at the first superfluous
+    /// component if too long, or past the last component if too short.
+    pub fn check_length(len: usize, expected_len: usize) -> Result<(), Self> {
+        if len == expected_len {
+            return Ok(());
+        }
+        Err(Self::new(
+            core::cmp::min(len, expected_len),
+            format!("{} components", expected_len),
+            CommonError::InvalidLength,
+        ))
+    }
+
+    /// Locates this invalid component in `input`, given the `spans` of all
+    /// components parsed from it.
+    pub fn located_in(self, input: impl AsRef<str>, spans: &[Span]) -> BIP32PathParseError {
+        let input = input.as_ref();
+        let span = spans
+            .get(self.index)
+            .copied()
+            .unwrap_or(Span::empty_at(input.len()));
+        BIP32PathParseError {
+            input: input.to_owned(),
+            component_index: self.index,
+            span,
+            expected: self.expected,
+            found: input[span.start..span.end].to_owned(),
+            reason: self.reason,
+        }
+    }
+}
+
+/// Error produced when parsing a BIP32 path string fails, retaining the
+/// original `input`, the index of the offending component and its byte
+/// `span` in the input, what was `expected` and what was `found` there.
+///
+/// If a component is missing, `span` is empty and points to the end of
+/// the input and `found` is empty.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+///
+/// let Err(CommonError::InvalidBIP32Path(error)) =
+///     CAP26AccountPath::from_bip32_string("m/44H/1022H/1H/525H/1460X/0H")
+/// else {
+///     panic!("Expected error")
+/// };
+/// assert_eq!(error.component_index, 4);
+/// assert_eq!(error.span, Span::new(20, 25));
+/// assert_eq!(error.found, "1460X");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, thiserror::Error)]
+#[error("Invalid component #{component_index} '{found}' at {span} in BIP32 path '{input}', expected {expected}: {reason}")]
+pub struct BIP32PathParseError {
+    pub input: String,
+    pub component_index: usize,
+    pub span: Span,
+    pub expected: String,
+    pub found: String,
+    pub reason: CommonError,
+}
+
+impl From<BIP32PathParseError> for CommonError {
+    fn from(value: BIP32PathParseError) -> Self {
+        Self::InvalidBIP32Path(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = BIP32PathParseError;
+
+    #[test]
+    fn located_in_found() {
+        let input = "m/44H/1022X";
+        let spans = [Span::new(2, 5), Span::new(6, 11)];
+        let sut = InvalidHDPathComponent::new(1, "1022H", CommonError::InvalidCoinType)
+            .located_in(input, &spans);
+        assert_eq!(
+            sut,
+            Sut {
+                input: input.to_owned(),
+                component_index: 1,
+                span: Span::new(6, 11),
+                expected: "1022H".to_owned(),
+                found: "1022X".to_owned(),
+                reason: CommonError::InvalidCoinType,
+            }
+        );
+    }
+
+    #[test]
+    fn located_in_missing() {
+        let input = "m/44H";
+        let spans = [Span::new(2, 5)];
+        let sut = InvalidHDPathComponent::new(1, "1022H", CommonError::InvalidLength)
+            .located_in(input, &spans);
+        assert_eq!(sut.span, Span::empty_at(5));
+        assert!(sut.span.is_empty());
+        assert_eq!(sut.found, "");
+    }
+
+    #[test]
+    fn check_length_too_short_points_past_end() {
+        assert_eq!(
+            InvalidHDPathComponent::check_length(1, 3)
+                .unwrap_err()
+                .index,
+            1
+        );
+    }
+
+    #[test]
+    fn check_length_too_long_points_at_first_superfluous() {
+        assert_eq!(
+            InvalidHDPathComponent::check_length(4, 3)
+                .unwrap_err()
+                .index,
+            3
+        );
+    }
+
+    #[test]
+    fn display() {
+        let sut = InvalidHDPathComponent::new(1, "1022H", CommonError::InvalidCoinType)
+            .located_in("m/44H/1022X", &[Span::new(2, 5), Span::new(6, 11)]);
+        assert_eq!(
+            sut.to_string(),
+            "Invalid component #1 '1022X' at 6..11 in BIP32 path 'm/44H/1022X', expected 1022H: Invalid CoinType"
+        );
+    }
+}
@@ -18,19 +18,31 @@ pub struct BIP44LikePath {
     index: HDPathComponent,
 }
 impl BIP44LikePath {
-    pub fn new(index: HDPathComponent) -> Self {
+    pub const fn new(index: HDPathComponent) -> Self {
         Self { index }
     }
 }
 
+impl HasSLIP10Curve for BIP44LikePath {
+    fn curve(&self) -> SLIP10Curve {
+        SLIP10Curve::Secp256k1
+    }
+}
+
+impl IsSecurityStateAware for BIP44LikePath {
+    fn is_securified(&self) -> bool {
+        self.index.is_securified()
+    }
+}
+
 impl HasSampleValues for BIP44LikePath {
     fn sample() -> Self {
-        Self::new(HDPathComponent::Unsecurified(Unsecurified::Unhardened(
+        Self::new(HDPathComponent::from(Unsecurified::Unhardened(
             Unhardened::from_local_key_space(0u32).unwrap(),
         )))
     }
     fn sample_other() -> Self {
-        Self::new(HDPathComponent::Unsecurified(Unsecurified::Hardened(
+        Self::new(HDPathComponent::from(Unsecurified::Hardened(
             UnsecurifiedHardened::from_local_key_space(1u32).unwrap(),
         )))
     }
@@ -51,28 +63,51 @@ impl From<BIP44LikePath> for HDPath {
 impl TryFrom<HDPath> for BIP44LikePath {
     type Error = CommonError;
     fn try_from(path: HDPath) -> Result<Self> {
-        let components = path.components();
+        Self::try_from_hd_path_components(&path).map_err(|e| e.reason)
+    }
+}
 
-        if components.len() != 5 {
-            return Err(CommonError::InvalidLength);
-        }
-        if components[0] != PURPOSE {
-            return Err(CommonError::InvalidPurpose);
+impl TryFromHDPathComponents for BIP44LikePath {
+    fn try_from_hd_path_components(path: &HDPath) -> Result<Self, InvalidHDPathComponent> {
+        let at = |index: usize| {
+            path.component(index).ok_or_else(|| {
+                InvalidHDPathComponent::new(index, "5 components", CommonError::InvalidLength)
+            })
+        };
+        if at(0)? != PURPOSE {
+            return Err(InvalidHDPathComponent::new(
+                0,
+                PURPOSE.to_string(),
+                CommonError::InvalidPurpose,
+            ));
         }
-        if components[1] != COIN_TYPE {
-            return Err(CommonError::InvalidCoinType);
+        if at(1)? != COIN_TYPE {
+            return Err(InvalidHDPathComponent::new(
+                1,
+                COIN_TYPE.to_string(),
+                CommonError::InvalidCoinType,
+            ));
         }
-        let bip44_account = components[2];
+        let bip44_account = at(2)?;
         if bip44_account.is_unhardened() {
-            return Err(CommonError::InvalidBIP44ExpectedAccountComponentToBeHardened);
+            return Err(InvalidHDPathComponent::new(
+                2,
+                "a hardened index",
+                CommonError::InvalidBIP44ExpectedAccountComponentToBeHardened,
+            ));
         }
-        let bip44_change = components[3];
+        let bip44_change = at(3)?;
 
         if bip44_change.is_hardened() {
-            return Err(CommonError::InvalidBIP44ExpectedChangeComponentToNotBeHardened);
+            return Err(InvalidHDPathComponent::new(
+                3,
+                "an unhardened index",
+                CommonError::InvalidBIP44ExpectedChangeComponentToNotBeHardened,
+            ));
         }
 
-        let index = components[4];
+        let index = at(4)?;
+        InvalidHDPathComponent::check_length(path.depth(), 5)?;
 
         Ok(Self::new(index))
     }
@@ -86,9 +121,15 @@ impl ToBIP32Str for BIP44LikePath {
         self.to_hd_path().to_bip32_string_debug()
     }
 }
+
+impl ToBIP32StrWithFormat for BIP44LikePath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        self.to_hd_path().to_bip32_string_with_format(format)
+    }
+}
 impl FromBIP32Str for BIP44LikePath {
     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-        HDPath::from_bip32_string(s).and_then(Self::try_from)
+        Self::from_bip32_string_validating_components(s)
     }
 }
 impl FromStr for BIP44LikePath {
@@ -141,7 +182,7 @@ mod tests {
         let sut = Sut::from_str("m/44H/1022H/0H/0/8H").unwrap();
         assert_eq!(
             sut.index,
-            HDPathComponent::Unsecurified(Unsecurified::Hardened(
+            HDPathComponent::from(Unsecurified::Hardened(
                 UnsecurifiedHardened::from_local_key_space(8u32).unwrap(),
             ))
         );
@@ -152,7 +193,7 @@ mod tests {
         let sut = Sut::from_str("m/44'/1022'/0'/0/8'").unwrap();
         assert_eq!(
             sut.index,
-            HDPathComponent::Unsecurified(Unsecurified::Hardened(
+            HDPathComponent::from(Unsecurified::Hardened(
                 UnsecurifiedHardened::from_local_key_space(8u32).unwrap(),
             ))
         );
@@ -163,7 +204,7 @@ mod tests {
         let sut = Sut::from_str("m/44H/1022H/0H/0/6").unwrap();
         assert_eq!(
             sut.index,
-            HDPathComponent::Unsecurified(Unsecurified::Unhardened(
+            HDPathComponent::from(Unsecurified::Unhardened(
                 Unhardened::from_local_key_space(6u32).unwrap(),
             ))
         );
@@ -174,12 +215,44 @@ mod tests {
         let sut = Sut::from_str("m/44'/1022'/0'/0/6").unwrap();
         assert_eq!(
             sut.index,
-            HDPathComponent::Unsecurified(Unsecurified::Unhardened(
+            HDPathComponent::from(Unsecurified::Unhardened(
                 Unhardened::from_local_key_space(6u32).unwrap(),
             ))
         );
     }
 
+    #[test]
+    fn from_str_hardened_change_is_located() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("m/44H/1022H/0H/0H/6") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 3);
+        assert_eq!(error.span, Span::new(15, 17));
+        assert_eq!(error.expected, "an unhardened index");
+        assert_eq!(error.found, "0H");
+        assert_eq!(
+            error.reason,
+            CommonError::InvalidBIP44ExpectedChangeComponentToNotBeHardened
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_coin_type_is_located() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("m/44H/1023H/0H/0/6") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 1);
+        assert_eq!(error.expected, "1022H");
+        assert_eq!(error.found, "1023H");
+        assert_eq!(error.reason, CommonError::InvalidCoinType);
+    }
+
+    #[test]
+    fn is_securified() {
+        assert!(!Sut::sample().is_securified());
+        assert!(Sut::new(HDPathComponent::sample_other()).is_securified());
+    }
+
     #[test]
     fn json_roundtrip_unhardened() {
         let sut = Sut::sample();
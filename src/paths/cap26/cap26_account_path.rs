@@ -26,12 +26,39 @@ impl IsNetworkAware for CAP26AccountPath {
     }
 }
 
+impl HasKeyKind for CAP26AccountPath {
+    fn key_kind(&self) -> CAP26KeyKind {
+        self.key_kind
+    }
+}
+
+impl HasSLIP10Curve for CAP26AccountPath {
+    fn curve(&self) -> SLIP10Curve {
+        SLIP10Curve::Curve25519
+    }
+}
+
 impl IsSecurityStateAware for CAP26AccountPath {
     fn is_securified(&self) -> bool {
         self.index.is_securified()
     }
 }
 
+impl CAP26AccountPath {
+    /// Const version of `NewEntityPath::new`.
+    pub(crate) const fn from_parts(
+        network_id: NetworkID,
+        key_kind: CAP26KeyKind,
+        index: Hardened,
+    ) -> Self {
+        Self {
+            network_id,
+            key_kind,
+            index,
+        }
+    }
+}
+
 impl NewEntityPath for CAP26AccountPath {
     fn new(
         network_id: impl Into<NetworkID>,
@@ -49,7 +76,7 @@ impl NewEntityPath for CAP26AccountPath {
 impl TryFrom<HDPath> for CAP26AccountPath {
     type Error = CommonError;
     fn try_from(path: HDPath) -> Result<Self> {
-        UnvalidatedCAP26Path::try_from(path).and_then(Self::try_from_unvalidated)
+        Self::try_from_hd_path_components(&path).map_err(|e| e.reason)
     }
 }
 impl HasSampleValues for CAP26AccountPath {
@@ -101,9 +128,15 @@ impl ToBIP32Str for CAP26AccountPath {
     }
 }
 
+impl ToBIP32StrWithFormat for CAP26AccountPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        self.to_hd_path().to_bip32_string_with_format(format)
+    }
+}
+
 impl FromBIP32Str for CAP26AccountPath {
     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-        HDPath::from_bip32_string(s).and_then(Self::try_from)
+        Self::from_bip32_string_validating_components(s)
     }
 }
 impl FromStr for CAP26AccountPath {
@@ -165,8 +198,26 @@ mod tests {
 
     #[test]
     fn from_str_persona() {
+        let Err(CommonError::InvalidBIP32Path(error)) =
+            Sut::from_str("m/44H/1022H/1H/618H/1460H/0H")
+        else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 3);
+        assert_eq!(error.span, Span::new(15, 19));
+        assert_eq!(
+            error.reason,
+            CommonError::WrongEntityKind {
+                expected: CAP26EntityKind::Account,
+                found: CAP26EntityKind::Identity
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_hd_path_wrong_entity_kind() {
         assert!(matches!(
-            Sut::from_str("m/44H/1022H/1H/618H/1460H/0H"),
+            Sut::try_from(HDPath::from_str("m/44H/1022H/1H/618H/1460H/0H").unwrap()),
             Err(CommonError::WrongEntityKind {
                 expected: CAP26EntityKind::Account,
                 found: CAP26EntityKind::Identity
@@ -174,6 +225,38 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn from_str_too_short_points_past_end() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("m/44H/1022H/1H") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 3);
+        assert_eq!(error.span, Span::empty_at(14));
+        assert_eq!(error.reason, CommonError::InvalidLength);
+    }
+
+    #[test]
+    fn from_str_strict() {
+        assert_eq!(
+            Sut::from_bip32_string_with_mode(
+                "m/44H/1022H/1H/525H/1460H/0H",
+                HDPathParsingMode::Strict
+            )
+            .unwrap(),
+            Sut::sample()
+        );
+        assert!(Sut::from_bip32_string_with_mode(
+            "m/44H/1022H/1H/525H/1460H//0H",
+            HDPathParsingMode::Strict
+        )
+        .is_err());
+        assert!(Sut::from_bip32_string_with_mode(
+            "m/44H/1022H/01H/525H/1460H/0H",
+            HDPathParsingMode::Strict
+        )
+        .is_err());
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = Sut::sample();
@@ -11,7 +11,10 @@ impl CAP26GetIDPath {
 
 impl From<CAP26GetIDPath> for HDPath {
     fn from(_: CAP26GetIDPath) -> Self {
-        Self::new(Vec::from_iter(CAP26GetIDPath::PATH))
+        Self::from_global_indices(
+            HDPathRoot::Private,
+            &CAP26GetIDPath::PATH.map(|c| c.map_to_global_key_space()),
+        )
     }
 }
 impl CAP26GetIDPath {
@@ -20,6 +23,12 @@ impl CAP26GetIDPath {
     }
 }
 
+impl HasSLIP10Curve for CAP26GetIDPath {
+    fn curve(&self) -> SLIP10Curve {
+        SLIP10Curve::Curve25519
+    }
+}
+
 impl ToBIP32Str for CAP26GetIDPath {
     fn to_bip32_string(&self) -> String {
         self.to_hd_path().to_bip32_string()
@@ -29,6 +38,12 @@ impl ToBIP32Str for CAP26GetIDPath {
     }
 }
 
+impl ToBIP32StrWithFormat for CAP26GetIDPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        self.to_hd_path().to_bip32_string_with_format(format)
+    }
+}
+
 use blake2::digest::{consts::U32, Digest};
 use blake2::Blake2b;
 
@@ -26,12 +26,39 @@ impl IsNetworkAware for CAP26IdentityPath {
     }
 }
 
+impl HasKeyKind for CAP26IdentityPath {
+    fn key_kind(&self) -> CAP26KeyKind {
+        self.key_kind
+    }
+}
+
+impl HasSLIP10Curve for CAP26IdentityPath {
+    fn curve(&self) -> SLIP10Curve {
+        SLIP10Curve::Curve25519
+    }
+}
+
 impl IsSecurityStateAware for CAP26IdentityPath {
     fn is_securified(&self) -> bool {
         self.index.is_securified()
     }
 }
 
+impl CAP26IdentityPath {
+    /// Const version of `NewEntityPath::new`.
+    pub(crate) const fn from_parts(
+        network_id: NetworkID,
+        key_kind: CAP26KeyKind,
+        index: Hardened,
+    ) -> Self {
+        Self {
+            network_id,
+            key_kind,
+            index,
+        }
+    }
+}
+
 impl NewEntityPath for CAP26IdentityPath {
     fn new(
         network_id: impl Into<NetworkID>,
@@ -49,7 +76,7 @@ impl NewEntityPath for CAP26IdentityPath {
 impl TryFrom<HDPath> for CAP26IdentityPath {
     type Error = CommonError;
     fn try_from(path: HDPath) -> Result<Self> {
-        UnvalidatedCAP26Path::try_from(path).and_then(Self::try_from_unvalidated)
+        Self::try_from_hd_path_components(&path).map_err(|e| e.reason)
     }
 }
 
@@ -102,9 +129,15 @@ impl ToBIP32Str for CAP26IdentityPath {
     }
 }
 
+impl ToBIP32StrWithFormat for CAP26IdentityPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        self.to_hd_path().to_bip32_string_with_format(format)
+    }
+}
+
 impl FromBIP32Str for CAP26IdentityPath {
     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-        HDPath::from_bip32_string(s).and_then(Self::try_from)
+        Self::from_bip32_string_validating_components(s)
     }
 }
 impl FromStr for CAP26IdentityPath {
@@ -166,8 +199,26 @@ mod tests {
 
     #[test]
     fn from_str_account() {
+        let Err(CommonError::InvalidBIP32Path(error)) =
+            Sut::from_str("m/44H/1022H/1H/525H/1460H/0H")
+        else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 3);
+        assert_eq!(error.span, Span::new(15, 19));
+        assert_eq!(
+            error.reason,
+            CommonError::WrongEntityKind {
+                expected: CAP26EntityKind::Identity,
+                found: CAP26EntityKind::Account
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_hd_path_wrong_entity_kind() {
         assert!(matches!(
-            Sut::from_str("m/44H/1022H/1H/525H/1460H/0H"),
+            Sut::try_from(HDPath::from_str("m/44H/1022H/1H/525H/1460H/0H").unwrap()),
             Err(CommonError::WrongEntityKind {
                 expected: CAP26EntityKind::Identity,
                 found: CAP26EntityKind::Account
@@ -175,6 +226,16 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn from_str_too_short_points_past_end() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("m/44H/1022H/1H") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 3);
+        assert_eq!(error.span, Span::empty_at(14));
+        assert_eq!(error.reason, CommonError::InvalidLength);
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = Sut::sample();
@@ -14,6 +14,12 @@ impl<T: HasEntityKind> HasEntityKindObjectSafe for T {
     }
 }
 
+/// The `CAP26KeyKind` of a path, dictating what the key derived at it is
+/// used for.
+pub trait HasKeyKind {
+    fn key_kind(&self) -> CAP26KeyKind;
+}
+
 pub trait NewEntityPath: Sized {
     fn new(
         network_id: impl Into<NetworkID>,
@@ -38,3 +44,13 @@ impl<T: HasEntityKind + NewEntityPath> NewEntityPathCheckingEntityKind for T {
         Ok(Self::new(path.network_id, path.key_kind, path.index))
     }
 }
+
+impl<T: HasEntityKind + NewEntityPath> TryFromHDPathComponents for T {
+    fn try_from_hd_path_components(path: &HDPath) -> Result<Self, InvalidHDPathComponent> {
+        UnvalidatedCAP26Path::try_from_hd_path_components_of_entity_kind(
+            path,
+            Some(Self::entity_kind()),
+        )
+        .map(|path| Self::new(path.network_id, path.key_kind, path.index))
+    }
+}
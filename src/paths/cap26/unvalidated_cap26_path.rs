@@ -14,23 +14,74 @@ impl TryFrom<HDPath> for UnvalidatedCAP26Path {
     type Error = CommonError;
 
     fn try_from(path: HDPath) -> Result<Self> {
-        let components = path.components();
-        if components.iter().any(|c| c.is_unhardened()) {
-            return Err(CommonError::CAP26DictatesThatAllIndicesMustBeHardened);
-        }
-        if components.len() != 6 {
-            return Err(CommonError::InvalidLength);
+        Self::try_from_hd_path_components(&path).map_err(|e| e.reason)
+    }
+}
+
+impl TryFromHDPathComponents for UnvalidatedCAP26Path {
+    fn try_from_hd_path_components(path: &HDPath) -> Result<Self, InvalidHDPathComponent> {
+        Self::try_from_hd_path_components_of_entity_kind(path, None)
+    }
+}
+
+impl UnvalidatedCAP26Path {
+    /// Validates the components of `path` in order, failing on the first
+    /// invalid one, so that the index of the error tells how much of the path
+    /// was valid. If `expected_entity_kind` is given the entity kind must
+    /// match it.
+    pub(crate) fn try_from_hd_path_components_of_entity_kind(
+        path: &HDPath,
+        expected_entity_kind: Option<CAP26EntityKind>,
+    ) -> Result<Self, InvalidHDPathComponent> {
+        let hardened_at = |index: usize| {
+            let component = path.component(index).ok_or_else(|| {
+                InvalidHDPathComponent::new(index, "6 components", CommonError::InvalidLength)
+            })?;
+            if component.is_unhardened() {
+                return Err(InvalidHDPathComponent::new(
+                    index,
+                    "a hardened index",
+                    CommonError::CAP26DictatesThatAllIndicesMustBeHardened,
+                ));
+            }
+            Ok(component)
+        };
+
+        if hardened_at(0)? != PURPOSE {
+            return Err(InvalidHDPathComponent::new(
+                0,
+                PURPOSE.to_string(),
+                CommonError::InvalidPurpose,
+            ));
         }
-        if components[0] != PURPOSE {
-            return Err(CommonError::InvalidPurpose);
+        if hardened_at(1)? != COIN_TYPE {
+            return Err(InvalidHDPathComponent::new(
+                1,
+                COIN_TYPE.to_string(),
+                CommonError::InvalidCoinType,
+            ));
         }
-        if components[1] != COIN_TYPE {
-            return Err(CommonError::InvalidCoinType);
+        let network_id = NetworkID::try_from(hardened_at(2)?.index_in_local_key_space())
+            .map_err(|e| InvalidHDPathComponent::new(2, "a NetworkID", e))?;
+        let entity_kind = CAP26EntityKind::try_from(hardened_at(3)?.index_in_local_key_space())
+            .map_err(|e| InvalidHDPathComponent::new(3, "a CAP26EntityKind", e))?;
+        if let Some(expected) = expected_entity_kind {
+            if entity_kind != expected {
+                return Err(InvalidHDPathComponent::new(
+                    3,
+                    HDPathComponent::from(expected).to_string(),
+                    CommonError::WrongEntityKind {
+                        expected,
+                        found: entity_kind,
+                    },
+                ));
+            }
         }
-        let network_id = NetworkID::try_from(components[2].index_in_local_key_space())?;
-        let entity_kind = CAP26EntityKind::try_from(components[3].index_in_local_key_space())?;
-        let key_kind = CAP26KeyKind::try_from(components[4].index_in_local_key_space())?;
-        let hardened = Hardened::try_from(components[5])?;
+        let key_kind = CAP26KeyKind::try_from(hardened_at(4)?.index_in_local_key_space())
+            .map_err(|e| InvalidHDPathComponent::new(4, "a CAP26KeyKind", e))?;
+        let hardened = Hardened::try_from(hardened_at(5)?)
+            .map_err(|e| InvalidHDPathComponent::new(5, "a hardened index", e))?;
+        InvalidHDPathComponent::check_length(path.depth(), 6)?;
 
         Ok(UnvalidatedCAP26Path {
             network_id,
@@ -109,6 +160,28 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn try_from_components_locates_unhardened() {
+        let path = HDPath::from_str("m/44H/1022H/1H/525/1460H/0H").unwrap();
+        assert_eq!(
+            Sut::try_from_hd_path_components(&path).unwrap_err(),
+            InvalidHDPathComponent::new(
+                3,
+                "a hardened index",
+                CommonError::CAP26DictatesThatAllIndicesMustBeHardened
+            )
+        );
+    }
+
+    #[test]
+    fn try_from_components_locates_invalid_key_kind() {
+        let path = HDPath::from_str("m/44H/1022H/1H/525H/22H/0H").unwrap();
+        assert_eq!(
+            Sut::try_from_hd_path_components(&path).unwrap_err().index,
+            4
+        );
+    }
+
     #[test]
     fn from_str_invalid_index_not_hardened() {
         assert!(matches!(
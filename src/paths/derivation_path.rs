@@ -1,94 +1,5 @@
 use crate::prelude::*;
 
-macro_rules! path_union {
-    (
-        $(
-            #[doc = $expr: expr]
-        )*
-        $union_name: ident,
-        $(
-            $variant_name: ident,
-            $variant_type: ty
-        )+
-    ) => {
-        paste::paste! {
-            $(
-                #[doc = $expr]
-            )*
-            #[derive(
-                Clone,
-                PartialEq,
-                EnumAsInner,
-                Eq,
-                Hash,
-                PartialOrd,
-                Ord,
-                SerializeDisplay,
-                DeserializeFromStr,
-                MoreDebug,
-                derive_more::Display,
-            )]
-            pub enum $union_name {
-                $(
-                    #[display("{value}")]
-                    #[debug("{:?}", value)]
-                    $variant_name { value: $variant_type },
-                )+
-            }
-
-            impl $union_name {
-                $(
-                    pub fn [< $variant_name:snake >](path: $variant_type) -> Self {
-                        Self::$variant_name { value: path }
-                    }
-                )+
-            }
-
-            impl FromStr for $union_name {
-                type Err = CommonError;
-                fn from_str(s: &str) -> Result<Self> {
-                    Self::from_bip32_string(s)
-                }
-            }
-
-            impl $union_name {
-                pub fn to_hd_path(&self) -> HDPath {
-                    match self {
-                        $(
-                            Self::$variant_name { value } => value.to_hd_path(),
-                        )+
-                    }
-                }
-            }
-
-            impl ToBIP32Str for $union_name {
-                fn to_bip32_string(&self) -> String {
-                    self.to_hd_path().to_bip32_string()
-                }
-                fn to_bip32_string_debug(&self) -> String {
-                    self.to_hd_path().to_bip32_string_debug()
-                }
-            }
-
-            impl FromBIP32Str for $union_name {
-                fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-                    let s = s.as_ref();
-                    let o = Result::<Self>::Err(CommonError::Overflow);
-
-                    o
-                    $(
-                        .or($variant_type::from_bip32_string(s).map(Self::[< $variant_name:snake >]))
-                    )+
-
-                }
-            }
-
-        }
-    };
-
-
-}
-
 path_union!(
     DerivationPath,
     Account, CAP26AccountPath
@@ -96,12 +7,57 @@ path_union!(
     BIP44Like, BIP44LikePath
 );
 
+impl DerivationPath {
+    /// The key kind of CAP26 paths, `None` for `BIP44LikePath`s which have
+    /// none.
+    pub fn key_kind(&self) -> Option<CAP26KeyKind> {
+        match self {
+            Self::Account { value } => Some(value.key_kind()),
+            Self::Identity { value } => Some(value.key_kind()),
+            Self::BIP44Like { .. } => None,
+        }
+    }
+
+    /// The previous name of `DerivationPath::bip44like`.
+    #[deprecated(note = "Renamed to `DerivationPath::bip44like`")]
+    pub fn b_i_p44_like(path: BIP44LikePath) -> Self {
+        Self::bip44like(path)
+    }
+}
+
+impl From<CAP26AccountPath> for DerivationPath {
+    fn from(path: CAP26AccountPath) -> Self {
+        Self::account(path)
+    }
+}
+
+impl From<CAP26IdentityPath> for DerivationPath {
+    fn from(path: CAP26IdentityPath) -> Self {
+        Self::identity(path)
+    }
+}
+
+impl From<BIP44LikePath> for DerivationPath {
+    fn from(path: BIP44LikePath) -> Self {
+        Self::bip44like(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     type Sut = DerivationPath;
 
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_b_i_p44_like_is_bip44like() {
+        assert_eq!(
+            Sut::b_i_p44_like(BIP44LikePath::sample()),
+            Sut::bip44like(BIP44LikePath::sample())
+        );
+    }
+
     #[test]
     fn test_to_bip32_string_is_display_account() {
         let sut = Sut::Account {
@@ -178,6 +134,85 @@ mod tests {
         assert_eq!(Sut::BIP44Like { value }, path2);
     }
 
+    #[test]
+    fn from_str_invalid_component_is_located() {
+        let Err(CommonError::InvalidPathUnion(error)) =
+            Sut::from_bip32_string("m/44H/1022H/1H/525H/1460X/0H")
+        else {
+            panic!("Expected error")
+        };
+        let Some(CommonError::InvalidBIP32Path(error)) =
+            error.rejections.first().map(|r| &r.reason)
+        else {
+            panic!("Expected positional error")
+        };
+        assert_eq!(error.component_index, 4);
+        assert_eq!(error.span, Span::new(20, 25));
+        assert_eq!(error.found, "1460X");
+    }
+
+    #[test]
+    fn from_str_lists_rejection_of_every_variant() {
+        let Err(CommonError::InvalidPathUnion(error)) =
+            Sut::from_bip32_string("m/44H/1022H/1H/618H/1460H/0")
+        else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.union, "DerivationPath");
+        assert_eq!(error.input, "m/44H/1022H/1H/618H/1460H/0");
+        assert_eq!(
+            error
+                .rejections
+                .iter()
+                .map(|r| (r.variant.as_str(), r.component_index()))
+                .collect::<Vec<_>>(),
+            vec![("Account", 3), ("Identity", 5), ("BIP44Like", 3)]
+        );
+    }
+
+    #[test]
+    fn from_str_almost_account_is_most_likely_account() {
+        let Err(CommonError::InvalidPathUnion(error)) =
+            Sut::from_bip32_string("m/44H/1022H/1H/525H/1460H/0")
+        else {
+            panic!("Expected error")
+        };
+        let most_likely = error.most_likely_intended().unwrap();
+        assert_eq!(most_likely.variant, "Account");
+        let CommonError::InvalidBIP32Path(reason) = &most_likely.reason else {
+            panic!("Expected positional error")
+        };
+        assert_eq!(
+            reason.reason,
+            CommonError::CAP26DictatesThatAllIndicesMustBeHardened
+        );
+        assert!(error
+            .to_string()
+            .contains("most likely intended as Account"));
+    }
+
+    #[test]
+    fn from_str_garbage_has_no_most_likely() {
+        let Err(CommonError::InvalidPathUnion(error)) = Sut::from_bip32_string("foobar") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.rejections.len(), 3);
+        assert_eq!(error.most_likely_intended(), None);
+    }
+
+    #[test]
+    fn from_str_strict() {
+        let s = "m/44H/1022H/0H/0/5";
+        assert_eq!(
+            Sut::from_bip32_string_with_mode(s, HDPathParsingMode::Strict).unwrap(),
+            Sut::from_bip32_string(s).unwrap()
+        );
+        assert!(
+            Sut::from_bip32_string_with_mode("44H/1022H/0H/0/5", HDPathParsingMode::Strict)
+                .is_err()
+        );
+    }
+
     #[test]
     fn string_roundtrip_getid_from_cap26() {
         let sut = Sut::BIP44Like {
@@ -187,4 +222,21 @@ mod tests {
         let value = BIP44LikePath::from_bip32_string(&s).unwrap();
         assert_eq!(Sut::BIP44Like { value }, sut)
     }
+
+    #[test]
+    fn try_into_variant() {
+        let sut = Sut::from(CAP26AccountPath::sample());
+        assert_eq!(
+            CAP26AccountPath::try_from(sut.clone()),
+            Ok(CAP26AccountPath::sample())
+        );
+        assert_eq!(
+            CAP26IdentityPath::try_from(sut),
+            Err(CommonError::WrongEntityKind {
+                expected: CAP26EntityKind::Identity,
+                found: CAP26EntityKind::Account,
+            })
+        );
+        assert!(BIP44LikePath::try_from(Sut::from(CAP26IdentityPath::sample())).is_err());
+    }
 }
@@ -2,35 +2,368 @@ use itertools::Itertools;
 
 use crate::prelude::*;
 
-#[allow(unused)]
-#[derive(
-    Clone,
-    PartialEq,
-    Eq,
-    Hash,
-    PartialOrd,
-    Ord,
-    Display,
-    MoreDebug,
-    DeserializeFromStr,
-    SerializeDisplay,
-)]
+/// A BIP32 path, a root `m` or `M` followed by any number of components,
+/// stored as their indices in the global key space. Paths of at most
+/// `INLINE_DEPTH` components, i.e. all real world paths, are stored inline,
+/// so that creating, cloning and iterating them never allocates.
+#[derive(Clone, Display, MoreDebug, DeserializeFromStr, SerializeDisplay)]
 #[display("{}", self.to_bip32_string())]
 #[debug("{}", self.to_bip32_string_debug())]
-pub struct HDPath(Vec<HDPathComponent>);
+pub struct HDPath {
+    indices: HDPathIndices,
+    root: HDPathRoot,
+}
+
+/// The global key space indices of the components of an `HDPath`, inline if
+/// there are at most `HDPath::INLINE_DEPTH` of them, else on the heap.
+#[derive(Clone)]
+enum HDPathIndices {
+    /// The first `depth` indices are used, the rest are always `0`.
+    Inline {
+        indices: [u32; HDPath::INLINE_DEPTH],
+        depth: u8,
+    },
+    Heap(Vec<u32>),
+}
+
+impl HDPathIndices {
+    fn as_slice(&self) -> &[u32] {
+        match self {
+            Self::Inline { indices, depth } => &indices[..*depth as usize],
+            Self::Heap(indices) => indices,
+        }
+    }
+
+    fn push(&mut self, index: u32) {
+        match self {
+            Self::Inline { indices, depth } if (*depth as usize) < HDPath::INLINE_DEPTH => {
+                indices[*depth as usize] = index;
+                *depth += 1;
+            }
+            Self::Inline { indices, .. } => {
+                let mut heap = Vec::with_capacity(HDPath::INLINE_DEPTH + 1);
+                heap.extend_from_slice(indices);
+                heap.push(index);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(indices) => indices.push(index),
+        }
+    }
+}
+
 impl HDPath {
-    pub const fn new(components: Vec<HDPathComponent>) -> Self {
-        Self(components)
+    /// BIP32 serializes the depth of a key as a single byte.
+    pub const MAX_DEPTH: usize = u8::MAX as usize;
+
+    /// The maximum number of components of a path stored inline, deeper
+    /// paths are stored on the heap. This is also the maximum depth of
+    /// paths created in const contexts, e.g. by `hd_path!`.
+    pub const INLINE_DEPTH: usize = 16;
+
+    /// The root `root` without any components.
+    pub const fn empty(root: HDPathRoot) -> Self {
+        Self {
+            indices: HDPathIndices::Inline {
+                indices: [0; Self::INLINE_DEPTH],
+                depth: 0,
+            },
+            root,
+        }
     }
-    pub fn components(&self) -> &[HDPathComponent] {
-        &self.0
+
+    /// A path of `components` from the master private key `m`.
+    pub fn new(components: impl IntoIterator<Item = HDPathComponent>) -> Self {
+        Self::with_root(HDPathRoot::Private, components)
+            .expect("Every component is derivable from `m`")
+    }
+
+    /// A path of `components` from the master public key `M`, fails if any
+    /// component is hardened or securified, since those cannot be derived
+    /// without the private key.
+    pub fn public(components: impl IntoIterator<Item = HDPathComponent>) -> Result<Self> {
+        Self::with_root(HDPathRoot::Public, components)
+    }
+
+    pub fn with_root(
+        root: HDPathRoot,
+        components: impl IntoIterator<Item = HDPathComponent>,
+    ) -> Result<Self> {
+        let mut path = Self::empty(root);
+        for component in components {
+            path.push(component)?;
+        }
+        Ok(path)
+    }
+
+    /// A path from `root` of the components with the global key space
+    /// `indices`, which must already be validated against `root`.
+    ///
+    /// # Panics
+    /// Panics if there are more than `INLINE_DEPTH` indices.
+    pub(crate) const fn from_global_indices(root: HDPathRoot, indices: &[u32]) -> Self {
+        assert!(indices.len() <= Self::INLINE_DEPTH, "Too many components");
+        let mut inline = [0; Self::INLINE_DEPTH];
+        let mut depth = 0;
+        while depth < indices.len() {
+            inline[depth] = indices[depth];
+            depth += 1;
+        }
+        Self {
+            indices: HDPathIndices::Inline {
+                indices: inline,
+                depth: depth as u8,
+            },
+            root,
+        }
+    }
+
+    /// Like `from_global_indices` but stores more than `INLINE_DEPTH`
+    /// indices on the heap.
+    fn from_validated_global_indices(root: HDPathRoot, indices: &[u32]) -> Self {
+        if indices.len() <= Self::INLINE_DEPTH {
+            Self::from_global_indices(root, indices)
+        } else {
+            Self {
+                indices: HDPathIndices::Heap(indices.to_vec()),
+                root,
+            }
+        }
+    }
+
+    fn push(&mut self, component: HDPathComponent) -> Result<()> {
+        if !self.root.can_derive(&component) {
+            return Err(CommonError::HardenedComponentInPublicDerivationPath);
+        }
+        self.indices.push(component.map_to_global_key_space());
+        Ok(())
+    }
+
+    /// The components of this path, without allocating.
+    pub fn components(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = HDPathComponent> + ExactSizeIterator + Clone + '_ {
+        self.global_indices()
+            .iter()
+            .map(|index| HDPathComponent::from_global(*index))
+    }
+
+    /// The component at `index`, `None` if `index` is not less than `depth`.
+    pub fn component(&self, index: usize) -> Option<HDPathComponent> {
+        self.global_indices()
+            .get(index)
+            .map(|index| HDPathComponent::from_global(*index))
+    }
+
+    /// The components of this path as indices in the global key space.
+    pub fn global_indices(&self) -> &[u32] {
+        self.indices.as_slice()
+    }
+
+    pub fn root(&self) -> HDPathRoot {
+        self.root
+    }
+
+    /// Whether this path is from the master public key `M`, i.e. derivable
+    /// without the seed.
+    pub fn is_public(&self) -> bool {
+        self.root.is_public()
+    }
+}
+
+impl PartialEq for HDPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.global_indices() == other.global_indices()
+    }
+}
+
+impl Eq for HDPath {}
+
+impl core::hash::Hash for HDPath {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.global_indices().hash(state);
+        self.root.hash(state);
+    }
+}
+
+impl PartialOrd for HDPath {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HDPath {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.global_indices()
+            .cmp(other.global_indices())
+            .then(self.root.cmp(&other.root))
     }
 }
 
 impl FromBIP32Str for HDPath {
     fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
-        let s = s.as_ref();
-        let mut s = s;
+        Self::parse_with_spans(s).map(|(path, _)| path)
+    }
+}
+
+impl HDPath {
+    pub fn from_bip32_string_with_mode(
+        s: impl AsRef<str>,
+        mode: HDPathParsingMode,
+    ) -> Result<Self> {
+        Self::parse_with_spans_in_mode(s, mode).map(|(path, _)| path)
+    }
+
+    pub(crate) fn parse_with_spans_in_mode(
+        s: impl AsRef<str>,
+        mode: HDPathParsingMode,
+    ) -> Result<(Self, Vec<Span>)> {
+        match mode {
+            HDPathParsingMode::Lenient => Self::parse_with_spans(s),
+            HDPathParsingMode::Strict => Self::parse_with_spans_strict(s.as_ref()),
+        }
+    }
+
+    /// Parses `s` into an `HDPath` together with the byte `Span` of each
+    /// component in `s`.
+    pub(crate) fn parse_with_spans(s: impl AsRef<str>) -> Result<(Self, Vec<Span>)> {
+        let input = s.as_ref();
+        let mut components = Vec::new();
+        let mut spans = Vec::new();
+        for (span, segment) in Self::segments_with_spans(input) {
+            spans.push(span);
+            let index = components.len();
+            let component = HDPathComponent::from_str(segment).map_err(|e| {
+                InvalidHDPathComponent::new(
+                    index,
+                    "an index optionally suffixed with H, h, ', S or ^, or a global index",
+                    e,
+                )
+                .located_in(input, &spans)
+            })?;
+            components.push(component);
+        }
+        Self::from_parsed(input, components, spans)
+    }
+
+    /// The `HDPath` of the `components` parsed from `input`, validated
+    /// against the root of `input`.
+    fn from_parsed(
+        input: &str,
+        components: Vec<HDPathComponent>,
+        spans: Vec<Span>,
+    ) -> Result<(Self, Vec<Span>)> {
+        let root = HDPathRoot::of(input);
+        root.validate(&components)
+            .map_err(|e| e.located_in(input, &spans))?;
+        let path = Self::with_root(root, components)?;
+        Ok((path, spans))
+    }
+
+    /// Like `parse_with_spans` but according to `HDPathParsingMode::Strict`.
+    fn parse_with_spans_strict(input: &str) -> Result<(Self, Vec<Span>)> {
+        let invalid = |index: usize, expected: &str, reason: CommonError, spans: &[Span]| {
+            CommonError::from(
+                InvalidHDPathComponent::new(index, expected, reason).located_in(input, spans),
+            )
+        };
+        let tail = match input {
+            "m" | "M" => return Ok((Self::empty(HDPathRoot::of(input)), Vec::new())),
+            _ => input
+                .strip_prefix("m/")
+                .or(input.strip_prefix("M/"))
+                .ok_or_else(|| {
+                    invalid(
+                        0,
+                        "root `m/`",
+                        CommonError::MissingRoot,
+                        &[Span::empty_at(0)],
+                    )
+                })?,
+        };
+
+        let mut offset = input.len() - tail.len();
+        let mut components = Vec::new();
+        let mut spans = Vec::new();
+        let mut style: Option<HDPathComponentStyle> = None;
+        for segment in tail.split(Self::SEPARATOR) {
+            let index = components.len();
+            let span = Span::new(offset, offset + segment.len());
+            offset = span.end + Self::SEPARATOR.len();
+            spans.push(span);
+
+            if index >= Self::MAX_DEPTH {
+                return Err(invalid(
+                    index,
+                    "no more components",
+                    CommonError::DepthExceedsMax,
+                    &spans,
+                ));
+            }
+            if segment.is_empty() {
+                return Err(invalid(
+                    index,
+                    "a component",
+                    CommonError::EmptyPathComponent,
+                    &spans,
+                ));
+            }
+            if segment.chars().any(char::is_whitespace) {
+                return Err(invalid(
+                    index,
+                    "no whitespace",
+                    CommonError::PathComponentContainsWhitespace,
+                    &spans,
+                ));
+            }
+            let digits = segment
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .unwrap_or_default();
+            if digits.len() > 1 && digits.starts_with('0') {
+                return Err(invalid(
+                    index,
+                    "no leading zeros",
+                    CommonError::PathComponentHasLeadingZeros,
+                    &spans,
+                ));
+            }
+            // A securified index with suffix `'` uses the same suffix as the
+            // non-canonical hardened ones.
+            let segment_style = HDPathComponentStyle::detect(segment).map(|style| match style {
+                HDPathComponentStyle::Apostrophe => HDPathComponentStyle::NonCanonical,
+                style => style,
+            });
+            if let Some(segment_style) = segment_style {
+                if *style.get_or_insert(segment_style) != segment_style {
+                    return Err(invalid(
+                        index,
+                        "same suffix notation as preceding components",
+                        CommonError::MixedCanonicalAndNonCanonicalSuffixes,
+                        &spans,
+                    ));
+                }
+            }
+            let component = HDPathComponent::from_str(segment).map_err(|e| {
+                invalid(
+                    index,
+                    "an index optionally suffixed with H, h, ', S or ^, or a global index",
+                    e,
+                    &spans,
+                )
+            })?;
+            components.push(component);
+        }
+        Self::from_parsed(input, components, spans)
+    }
+
+    /// Strips any leading `m/`, `M/` or `/` from `input`, or all of it if it
+    /// is just the root `m` or `M`, and splits the rest into its non-empty
+    /// segments, together with their `Span` in `input`.
+    pub(crate) fn segments_with_spans(input: &str) -> impl Iterator<Item = (Span, &str)> {
+        let mut s = input;
+        if s == "m" || s == "M" {
+            s = ""
+        }
         if s.starts_with("m/") {
             s = &s[2..]
         }
@@ -40,12 +373,12 @@ impl FromBIP32Str for HDPath {
         if s.starts_with("/") {
             s = &s[1..]
         }
-        let components = s
-            .split(Self::SEPARATOR)
-            .filter(|s| !s.is_empty())
-            .map(HDPathComponent::from_str)
-            .collect::<Result<Vec<_>>>()?;
-        Ok(Self(components))
+        let mut offset = input.len() - s.len();
+        s.split(Self::SEPARATOR).filter_map(move |segment| {
+            let span = Span::new(offset, offset + segment.len());
+            offset = span.end + Self::SEPARATOR.len();
+            (!segment.is_empty()).then_some((span, segment))
+        })
     }
 }
 
@@ -55,13 +388,98 @@ impl HDPath {
     where
         F: Fn(&HDPathComponent) -> String,
     {
-        let head = "m".to_owned();
-        let mut path = vec![head];
-        let tail = self.0.iter().map(map);
-        path.extend(tail.collect_vec());
+        Self::components_to_string_map(Some(&self.root.to_string()), self.components(), map)
+    }
+
+    pub(crate) fn components_to_string_map<F>(
+        head: Option<&str>,
+        components: impl IntoIterator<Item = HDPathComponent>,
+        map: F,
+    ) -> String
+    where
+        F: Fn(&HDPathComponent) -> String,
+    {
+        let mut path = head.map(str::to_owned).into_iter().collect_vec();
+        let tail = components.into_iter().map(|c| map(&c));
+        path.extend(tail);
         path.into_iter().join(Self::SEPARATOR)
     }
 }
+
+impl HDPath {
+    /// The number of components in this path, `0` for the root `m` or `M`.
+    pub fn depth(&self) -> usize {
+        self.global_indices().len()
+    }
+
+    /// This path without its last component, `None` for the root `m` or `M`.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.global_indices().split_last()?;
+        Some(Self::from_validated_global_indices(self.root, parent))
+    }
+
+    /// This path with `component` appended, fails if `component` is hardened
+    /// or securified and this path is from the public root `M`.
+    pub fn child(&self, component: impl Into<HDPathComponent>) -> Result<Self> {
+        let mut child = self.clone();
+        child.push(component.into())?;
+        Ok(child)
+    }
+
+    /// Whether `other` is a descendant of this path, i.e. has the same root,
+    /// starts with all the components of this path and has more components.
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        self.root == other.root
+            && other.depth() > self.depth()
+            && other.global_indices().starts_with(self.global_indices())
+    }
+
+    /// The components of this path after `prefix`, `None` if this path does
+    /// not start with `prefix`, including its root.
+    pub fn strip_prefix(&self, prefix: &Self) -> Option<RelativeHDPath> {
+        if self.root != prefix.root {
+            return None;
+        }
+        self.global_indices()
+            .strip_prefix(prefix.global_indices())
+            .map(|tail| {
+                RelativeHDPath::new(
+                    tail.iter()
+                        .map(|index| HDPathComponent::from_global(*index))
+                        .collect(),
+                )
+            })
+    }
+
+    /// This path with all components of `tail` appended, fails if any of them
+    /// is hardened or securified and this path is from the public root `M`.
+    pub fn join(&self, tail: impl IntoIterator<Item = HDPathComponent>) -> Result<Self> {
+        let mut joined = self.clone();
+        for component in tail {
+            joined.push(component)?;
+        }
+        Ok(joined)
+    }
+
+    /// The longest path which both this path and `other` start with, `None`
+    /// if they have different roots.
+    pub fn common_prefix(&self, other: &Self) -> Option<Self> {
+        if self.root != other.root {
+            return None;
+        }
+        let depth = self
+            .global_indices()
+            .iter()
+            .zip(other.global_indices())
+            .take_while(|(lhs, rhs)| lhs == rhs)
+            .count();
+        Some(Self::from_validated_global_indices(
+            self.root,
+            &self.global_indices()[..depth],
+        ))
+    }
+}
+
 impl ToBIP32Str for HDPath {
     fn to_bip32_string(&self) -> String {
         self.to_string_map(|c| format!("{}", c))
@@ -71,6 +489,12 @@ impl ToBIP32Str for HDPath {
     }
 }
 
+impl ToBIP32StrWithFormat for HDPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        format.format_components(Some(self.root), self.components())
+    }
+}
+
 impl FromStr for HDPath {
     type Err = CommonError;
 
@@ -96,6 +520,424 @@ mod tests {
         // assert_eq!(Sut::default().to_string(), "");
     }
 
+    #[test]
+    fn from_str_invalid_component_is_located() {
+        let input = "m/44H/1022H/1H/525H/foo/0H";
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str(input) else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.input, input);
+        assert_eq!(error.component_index, 4);
+        assert_eq!(error.span, Span::new(20, 23));
+        assert_eq!(error.found, "foo");
+        assert_eq!(error.reason, CommonError::NonU32Str);
+    }
+
+    #[test]
+    fn from_str_invalid_component_is_located_skipping_empty() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("/44H//2X") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 1);
+        assert_eq!(error.span, Span::new(6, 8));
+        assert_eq!(error.found, "2X");
+    }
+
+    #[test]
+    fn parse_with_spans() {
+        let (path, spans) = Sut::parse_with_spans("m/44H/0/5S").unwrap();
+        assert_eq!(path.components().len(), 3);
+        assert_eq!(
+            spans,
+            vec![Span::new(2, 5), Span::new(6, 7), Span::new(8, 10)]
+        );
+    }
+
+    fn path(s: &str) -> Sut {
+        Sut::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn depth() {
+        assert_eq!(path("m").depth(), 0);
+        assert_eq!(path("m/44H/1022H").depth(), 2);
+    }
+
+    #[test]
+    fn parent() {
+        assert_eq!(path("m/44H/1022H").parent(), Some(path("m/44H")));
+        assert_eq!(path("m/44H").parent(), Some(path("m")));
+        assert_eq!(path("m").parent(), None);
+    }
+
+    #[test]
+    fn child() {
+        assert_eq!(
+            path("m/44H")
+                .child(HDPathComponent::from_str("1022H").unwrap())
+                .unwrap(),
+            path("m/44H/1022H")
+        );
+    }
+
+    #[test]
+    fn child_of_parent_is_self() {
+        let sut = CAP26AccountPath::sample().to_hd_path();
+        let last = sut.components().last().unwrap();
+        assert_eq!(sut.parent().unwrap().child(last).unwrap(), sut);
+    }
+
+    #[test]
+    fn is_ancestor_of() {
+        assert!(path("m").is_ancestor_of(&path("m/44H")));
+        assert!(path("m/44H").is_ancestor_of(&path("m/44H/1022H/1H")));
+        assert!(!path("m/44H").is_ancestor_of(&path("m/44H")));
+        assert!(!path("m/44H/1022H").is_ancestor_of(&path("m/44H")));
+        assert!(!path("m/44H").is_ancestor_of(&path("m/45H/1022H")));
+    }
+
+    #[test]
+    fn strip_prefix() {
+        assert_eq!(
+            path("m/44H/1022H/0H/0/5").strip_prefix(&path("m/44H/1022H")),
+            Some(RelativeHDPath::from_str("0H/0/5").unwrap())
+        );
+        assert_eq!(
+            path("m/44H").strip_prefix(&path("m/44H")),
+            Some(RelativeHDPath::default())
+        );
+        assert_eq!(path("m/44H").strip_prefix(&path("m/1022H")), None);
+    }
+
+    #[test]
+    fn join_relative() {
+        let tail = RelativeHDPath::from_str("0/5").unwrap();
+        assert_eq!(
+            path("m/44H/1022H/0H").join(&tail).unwrap(),
+            path("m/44H/1022H/0H/0/5")
+        );
+    }
+
+    #[test]
+    fn join_hd_path() {
+        assert_eq!(
+            path("m/44H").join(path("m/1022H/0H").components()).unwrap(),
+            path("m/44H/1022H/0H")
+        );
+    }
+
+    #[test]
+    fn strip_prefix_then_join_is_self() {
+        let sut = path("m/44H/1022H/1H/525H/1460H/0S");
+        let prefix = path("m/44H/1022H/1H");
+        assert_eq!(
+            prefix.join(&sut.strip_prefix(&prefix).unwrap()).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn common_prefix() {
+        assert_eq!(
+            CAP26AccountPath::sample()
+                .to_hd_path()
+                .common_prefix(&CAP26IdentityPath::sample().to_hd_path()),
+            Some(path("m/44H/1022H/1H"))
+        );
+        assert_eq!(path("m/44H").common_prefix(&path("m/1H")), Some(path("m")));
+        assert_eq!(path("m/0").common_prefix(&path("M/0")), None);
+    }
+
+    #[test]
+    fn public_root() {
+        let sut = path("M/0/5");
+        assert!(sut.is_public());
+        assert_eq!(sut.root(), HDPathRoot::Public);
+        assert_eq!(sut.to_string(), "M/0/5");
+        assert_eq!(format!("{:?}", sut), "M/0/5");
+        assert!(!path("m/0/5").is_public());
+        assert_ne!(sut, path("m/0/5"));
+    }
+
+    #[test]
+    fn public_root_only() {
+        assert_eq!(path("M").depth(), 0);
+        assert!(path("M").is_public());
+        assert_eq!(path("M").to_string(), "M");
+        assert!(strict("M").unwrap().is_public());
+    }
+
+    #[test]
+    fn public_root_rejects_hardened_and_securified() {
+        for (input, index, span) in [("M/0/5H", 1, Span::new(4, 6)), ("M/7S", 0, Span::new(2, 4))] {
+            let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str(input) else {
+                panic!("Expected error")
+            };
+            assert_eq!(error.component_index, index);
+            assert_eq!(error.span, span);
+            assert_eq!(
+                error.reason,
+                CommonError::HardenedComponentInPublicDerivationPath
+            );
+            assert_eq!(
+                strict_error(input).reason,
+                CommonError::HardenedComponentInPublicDerivationPath
+            );
+        }
+    }
+
+    #[test]
+    fn public_constructor() {
+        let unhardened = HDPathComponent::from_str("0").unwrap();
+        assert_eq!(Sut::public(vec![unhardened]).unwrap(), path("M/0"));
+        assert_eq!(
+            Sut::public(vec![HDPathComponent::sample_other()]),
+            Err(CommonError::HardenedComponentInPublicDerivationPath)
+        );
+    }
+
+    #[test]
+    fn public_navigation() {
+        let sut = path("M/0");
+        assert_eq!(sut.parent(), Some(path("M")));
+        assert_eq!(
+            sut.child(HDPathComponent::from_str("5").unwrap()).unwrap(),
+            path("M/0/5")
+        );
+        assert_eq!(
+            sut.child(HDPathComponent::from_str("5H").unwrap()),
+            Err(CommonError::HardenedComponentInPublicDerivationPath)
+        );
+        assert_eq!(
+            sut.join(&RelativeHDPath::from_str("1/5S").unwrap()),
+            Err(CommonError::HardenedComponentInPublicDerivationPath)
+        );
+        assert!(path("M").is_ancestor_of(&sut));
+        assert!(!path("m").is_ancestor_of(&sut));
+        assert_eq!(path("M/0/5").strip_prefix(&path("m/0")), None);
+    }
+
+    #[test]
+    fn public_json_roundtrip() {
+        assert_json_roundtrip(&path("M/0/5"));
+    }
+
+    fn strict(s: &str) -> Result<Sut> {
+        Sut::from_bip32_string_with_mode(s, HDPathParsingMode::Strict)
+    }
+
+    fn strict_error(s: &str) -> BIP32PathParseError {
+        let Err(CommonError::InvalidBIP32Path(error)) = strict(s) else {
+            panic!("Expected error")
+        };
+        *error
+    }
+
+    #[test]
+    fn strict_valid() {
+        assert_eq!(
+            strict("m/44H/1022H/1H/525H/1460H/0S").unwrap(),
+            path("m/44H/1022H/1H/525H/1460H/0S")
+        );
+        assert_eq!(
+            strict("m/44'/1022'/0'/0/0'").unwrap(),
+            path("m/44H/1022H/0H/0/0H")
+        );
+        assert_eq!(strict("m/0/10/100").unwrap(), path("m/0/10/100"));
+        assert_eq!(strict("m").unwrap(), path("m"));
+    }
+
+    #[test]
+    fn strict_rejects_missing_root() {
+        let error = strict_error("44H/1022H");
+        assert_eq!(error.reason, CommonError::MissingRoot);
+        assert_eq!(error.span, Span::empty_at(0));
+        assert_eq!(strict_error("/44H").reason, CommonError::MissingRoot);
+    }
+
+    #[test]
+    fn strict_rejects_empty_segments() {
+        let error = strict_error("m//44H///0");
+        assert_eq!(error.reason, CommonError::EmptyPathComponent);
+        assert_eq!(error.component_index, 0);
+        assert_eq!(error.span, Span::empty_at(2));
+        assert_eq!(
+            strict_error("m/44H/").reason,
+            CommonError::EmptyPathComponent
+        );
+    }
+
+    #[test]
+    fn strict_rejects_whitespace() {
+        let error = strict_error("m/44H/ 1022H");
+        assert_eq!(error.reason, CommonError::PathComponentContainsWhitespace);
+        assert_eq!(error.found, " 1022H");
+    }
+
+    #[test]
+    fn strict_rejects_leading_zeros() {
+        let error = strict_error("m/44H/01022H");
+        assert_eq!(error.reason, CommonError::PathComponentHasLeadingZeros);
+        assert_eq!(error.component_index, 1);
+        assert_eq!(
+            strict_error("m/00").reason,
+            CommonError::PathComponentHasLeadingZeros
+        );
+    }
+
+    #[test]
+    fn strict_leading_zero_only_in_leading_digits() {
+        for s in ["m/0x5", "m/0a1"] {
+            assert_ne!(
+                strict_error(s).reason,
+                CommonError::PathComponentHasLeadingZeros
+            );
+        }
+        assert_eq!(strict("m/0H").unwrap(), path("m/0H"));
+    }
+
+    #[test]
+    fn strict_rejects_mixed_suffixes() {
+        let error = strict_error("m/44H/1022'/0H");
+        assert_eq!(
+            error.reason,
+            CommonError::MixedCanonicalAndNonCanonicalSuffixes
+        );
+        assert_eq!(error.component_index, 1);
+        assert_eq!(
+            strict_error("m/44'/5S").reason,
+            CommonError::MixedCanonicalAndNonCanonicalSuffixes
+        );
+    }
+
+    #[test]
+    fn strict_mixed_with_lowercase_h_or_global() {
+        assert_eq!(
+            strict_error("m/44H/1022h").reason,
+            CommonError::MixedCanonicalAndNonCanonicalSuffixes
+        );
+        assert_eq!(
+            strict_error("m/2147483692/1022H").reason,
+            CommonError::MixedCanonicalAndNonCanonicalSuffixes
+        );
+        assert_eq!(
+            strict_error("m/44h/2147484670/0'").reason,
+            CommonError::MixedCanonicalAndNonCanonicalSuffixes
+        );
+        assert_eq!(strict("m/44h/1022h/0").unwrap(), path("m/44H/1022H/0"));
+        assert_eq!(
+            strict("m/2147483692/2147484670").unwrap(),
+            path("m/44H/1022H")
+        );
+    }
+
+    #[test]
+    fn strict_apostrophe_for_hardened_and_securified() {
+        assert_eq!(strict("m/44'/1073741824'/0^").unwrap(), path("m/44H/0S/0S"));
+    }
+
+    #[test]
+    fn from_str_lowercase_h_and_global() {
+        assert_eq!(
+            path("m/44h/1022h/2147483648/3221225472"),
+            path("m/44H/1022H/0H/0S")
+        );
+        assert_eq!(path("m/44h/2147484670").to_string(), "m/44H/1022H");
+    }
+
+    #[test]
+    fn strict_depth() {
+        let max = format!("m{}", "/0".repeat(Sut::MAX_DEPTH));
+        assert_eq!(strict(&max).unwrap().depth(), Sut::MAX_DEPTH);
+
+        let error = strict_error(&format!("{}/0", max));
+        assert_eq!(error.reason, CommonError::DepthExceedsMax);
+        assert_eq!(error.component_index, Sut::MAX_DEPTH);
+    }
+
+    #[test]
+    fn lenient_is_default_and_still_lenient() {
+        assert_eq!(
+            Sut::from_bip32_string_with_mode("m//44H///0", HDPathParsingMode::default()).unwrap(),
+            path("m/44H/0")
+        );
+        assert_eq!(path("44H/0"), path("m/44H/0"));
+    }
+
+    #[test]
+    fn lenient_depth() {
+        let deep = path(&format!("m{}", "/0".repeat(Sut::MAX_DEPTH + 1)));
+        assert_eq!(deep.depth(), Sut::MAX_DEPTH + 1);
+    }
+
+    #[test]
+    fn deeper_than_inline_depth() {
+        let components = (0..=Sut::INLINE_DEPTH as u32)
+            .map(HDPathComponent::from_global)
+            .collect::<Vec<_>>();
+        let deep = Sut::new(components.clone());
+        assert_eq!(deep.depth(), Sut::INLINE_DEPTH + 1);
+        assert!(deep.components().eq(components.iter().copied()));
+        assert_eq!(path(&deep.to_string()), deep);
+        assert_eq!(strict(&deep.to_string()).unwrap(), deep);
+
+        let inline = Sut::new(components[..Sut::INLINE_DEPTH].iter().copied());
+        let parent = deep.parent().unwrap();
+        assert_eq!(parent, inline);
+        assert_eq!(HashSet::from([parent, inline.clone()]).len(), 1);
+        assert_eq!(inline.child(components[Sut::INLINE_DEPTH]).unwrap(), deep);
+        assert_eq!(deep.common_prefix(&inline), Some(inline));
+    }
+
+    #[test]
+    fn deeper_than_max_depth() {
+        let zero = HDPathComponent::from_str("0").unwrap();
+        let deep = Sut::new([zero; Sut::MAX_DEPTH + 1]);
+        assert_eq!(deep.depth(), Sut::MAX_DEPTH + 1);
+        assert_eq!(deep.child(zero).unwrap().depth(), Sut::MAX_DEPTH + 2);
+        assert_eq!(
+            path("m/0").join(deep.components()).unwrap().depth(),
+            Sut::MAX_DEPTH + 2
+        );
+    }
+
+    #[test]
+    fn component() {
+        let sut = path("m/44H/1022H/0S");
+        assert_eq!(
+            sut.component(1),
+            Some(HDPathComponent::from_str("1022H").unwrap())
+        );
+        assert_eq!(sut.component(3), None);
+    }
+
+    #[test]
+    fn global_indices() {
+        assert_eq!(
+            path("m/44H/0/0S").global_indices(),
+            [
+                GLOBAL_OFFSET_HARDENED + 44,
+                0,
+                GLOBAL_OFFSET_HARDENED_SECURIFIED
+            ]
+        );
+        assert!(path("m").global_indices().is_empty());
+    }
+
+    #[test]
+    fn components_roundtrip() {
+        let sut = CAP26AccountPath::sample().to_hd_path();
+        assert_eq!(Sut::new(sut.components()), sut);
+        assert_eq!(sut.components().next_back(), sut.component(sut.depth() - 1));
+    }
+
+    #[test]
+    fn ord_by_components_then_root() {
+        assert!(path("m/0") < path("m/1"));
+        assert!(path("m/0") < path("m/0/0"));
+        assert!(path("m/0") < path("M/0"));
+    }
+
     #[test]
     fn account_path() {
         let hdpath = Sut::from_str("m/44H/1022H/1H/525H/1460H/0H").unwrap();
@@ -0,0 +1,328 @@
+use crate::prelude::*;
+
+/// How each `HDPathComponent` is spelled when formatting a path.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum HDPathComponentStyle {
+    /// Local index with suffix `H` for hardened and `S` for securified, e.g.
+    /// `5H` and `5S`, as used by `Display`.
+    #[default]
+    Canonical,
+
+    /// Local index with suffix `'` for hardened and `^` for securified, e.g.
+    /// `5'` and `5^`, as used by `Debug`.
+    NonCanonical,
+
+    /// Local index with suffix `h` for hardened, as used by BIP380 output
+    /// descriptors. Securified indices are written as their hardened value,
+    /// e.g. `0S` as `1073741824h`.
+    LowercaseH,
+
+    /// Local index with suffix `'` for hardened. Securified indices are
+    /// written as their hardened value, e.g. `0S` as `1073741824'`, for tools
+    /// unaware of securified indices.
+    Apostrophe,
+
+    /// The index in the global key space in decimal, e.g. `2147483653` for
+    /// `5H`.
+    Global,
+
+    /// The index in the global key space in hex, e.g. `0x80000005` for `5H`.
+    GlobalHex,
+}
+
+impl HDPathComponentStyle {
+    /// The style `component` is written in, `None` if it is not a valid
+    /// component, or an unhardened index which is the same in all styles but
+    /// `GlobalHex`. A hardened index suffixed with `'` is `NonCanonical`, being
+    /// the same in `Apostrophe`, unless it is a securified index written as
+    /// its hardened value.
+    pub fn detect(component: &str) -> Option<Self> {
+        let parsed = HDPathComponent::from_bip32_string(component).ok()?;
+        let suffix = component.trim_start_matches(|c: char| c.is_ascii_digit());
+        if suffix == UnsecurifiedHardened::CANONICAL_SUFFIX
+            || suffix == SecurifiedU30::CANONICAL_SUFFIX
+        {
+            Some(Self::Canonical)
+        } else if suffix == SecurifiedU30::NON_CANONICAL_SUFFIXES {
+            Some(Self::NonCanonical)
+        } else if suffix == UnsecurifiedHardened::NON_CANONICAL_SUFFIXES {
+            Some(if parsed.key_space().is_securified() {
+                Self::Apostrophe
+            } else {
+                Self::NonCanonical
+            })
+        } else if suffix == UNSECURIFIED_HARDENED_LOWERCASE_SUFFIX {
+            Some(Self::LowercaseH)
+        } else if parsed.is_hardened() {
+            Some(Self::Global)
+        } else {
+            None
+        }
+    }
+
+    /// Whether this is the `Canonical` style, used by `Display`.
+    pub fn is_canonical(&self) -> bool {
+        *self == Self::Canonical
+    }
+}
+
+/// Configures how a BIP32 path is formatted as a string, see
+/// `ToBIP32StrWithFormat`.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+///
+/// let path = CAP26AccountPath::sample();
+/// assert_eq!(
+///     path.to_bip32_string_with_format(HDPathFormat::new(HDPathComponentStyle::LowercaseH)),
+///     "m/44h/1022h/1h/525h/1460h/0h"
+/// );
+/// assert_eq!(
+///     path.to_bip32_string_with_format(HDPathFormat::new(HDPathComponentStyle::GlobalHex).without_root()),
+///     "0x8000002c/0x800003fe/0x80000001/0x8000020d/0x800005b4/0x80000000"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HDPathFormat {
+    pub style: HDPathComponentStyle,
+    pub include_root: bool,
+}
+
+impl Default for HDPathFormat {
+    fn default() -> Self {
+        Self::CANONICAL
+    }
+}
+
+impl HDPathFormat {
+    /// Same as `Display`, e.g. `m/44H/1022H/0S`.
+    pub const CANONICAL: Self = Self::new(HDPathComponentStyle::Canonical);
+
+    /// Same as `Debug`, e.g. `m/44'/1022'/0^`.
+    pub const NON_CANONICAL: Self = Self::new(HDPathComponentStyle::NonCanonical);
+
+    pub const fn new(style: HDPathComponentStyle) -> Self {
+        Self {
+            style,
+            include_root: true,
+        }
+    }
+
+    /// This format but without the leading `m/`.
+    pub const fn without_root(self) -> Self {
+        Self {
+            include_root: false,
+            ..self
+        }
+    }
+
+    pub fn format_component(&self, component: &HDPathComponent) -> String {
+        let global = component.map_to_global_key_space();
+        let local = u32::from(component.index_in_local_key_space());
+        // The local index of a securified component in the hardened key space.
+        let as_hardened = global.saturating_sub(GLOBAL_OFFSET_HARDENED);
+        match (self.style, component.key_space()) {
+            (HDPathComponentStyle::Global, _) => global.to_string(),
+            (HDPathComponentStyle::GlobalHex, _) => format!("{:#x}", global),
+            (_, KeySpace::Unsecurified { is_hardened: false }) => local.to_string(),
+            (HDPathComponentStyle::Canonical, _) => component.to_string(),
+            (HDPathComponentStyle::NonCanonical, _) => format!("{:?}", component),
+            (HDPathComponentStyle::LowercaseH, _) => format!("{}h", as_hardened),
+            (HDPathComponentStyle::Apostrophe, _) => format!(
+                "{}{}",
+                as_hardened,
+                UnsecurifiedHardened::NON_CANONICAL_SUFFIXES
+            ),
+        }
+    }
+
+    /// Formats `components` after `root`, if any and `include_root`.
+    pub fn format_components(
+        &self,
+        root: Option<HDPathRoot>,
+        components: impl IntoIterator<Item = HDPathComponent>,
+    ) -> String {
+        let head = root
+            .filter(|_| self.include_root)
+            .map(|root| root.to_string());
+        HDPath::components_to_string_map(head.as_deref(), components, |c| self.format_component(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = HDPathFormat;
+
+    fn format(style: HDPathComponentStyle, path: &str) -> String {
+        HDPath::from_str(path)
+            .unwrap()
+            .to_bip32_string_with_format(Sut::new(style))
+    }
+
+    const PATH: &str = "m/44H/1022H/0/7S";
+
+    #[test]
+    fn canonical_is_display() {
+        let path = HDPath::from_str(PATH).unwrap();
+        assert_eq!(
+            path.to_bip32_string_with_format(Sut::CANONICAL),
+            path.to_string()
+        );
+        assert_eq!(
+            path.to_bip32_string_with_format(Sut::default()),
+            path.to_string()
+        );
+    }
+
+    #[test]
+    fn non_canonical_is_debug() {
+        let path = HDPath::from_str(PATH).unwrap();
+        assert_eq!(
+            path.to_bip32_string_with_format(Sut::NON_CANONICAL),
+            format!("{:?}", path)
+        );
+    }
+
+    #[test]
+    fn lowercase_h() {
+        assert_eq!(
+            format(HDPathComponentStyle::LowercaseH, PATH),
+            "m/44h/1022h/0/1073741831h"
+        );
+    }
+
+    #[test]
+    fn apostrophe() {
+        assert_eq!(
+            format(HDPathComponentStyle::Apostrophe, PATH),
+            "m/44'/1022'/0/1073741831'"
+        );
+    }
+
+    #[test]
+    fn lowercase_h_and_apostrophe_round_trip() {
+        let path = HDPath::from_str(PATH).unwrap();
+        for style in [
+            HDPathComponentStyle::LowercaseH,
+            HDPathComponentStyle::Apostrophe,
+            HDPathComponentStyle::Global,
+        ] {
+            let formatted = path.to_bip32_string_with_format(Sut::new(style));
+            assert_eq!(HDPath::from_str(&formatted).unwrap(), path);
+        }
+    }
+
+    #[test]
+    fn detect() {
+        use HDPathComponentStyle::*;
+        assert_eq!(HDPathComponentStyle::detect("5H"), Some(Canonical));
+        assert_eq!(HDPathComponentStyle::detect("5S"), Some(Canonical));
+        assert_eq!(HDPathComponentStyle::detect("5'"), Some(NonCanonical));
+        assert_eq!(HDPathComponentStyle::detect("5^"), Some(NonCanonical));
+        assert_eq!(HDPathComponentStyle::detect("5h"), Some(LowercaseH));
+        assert_eq!(
+            HDPathComponentStyle::detect("1073741829'"),
+            Some(Apostrophe)
+        );
+        assert_eq!(HDPathComponentStyle::detect("2147483653"), Some(Global));
+        assert_eq!(HDPathComponentStyle::detect("5"), None);
+        assert_eq!(HDPathComponentStyle::detect("5X"), None);
+    }
+
+    #[test]
+    fn is_canonical() {
+        assert!(HDPathComponentStyle::Canonical.is_canonical());
+        assert!(!HDPathComponentStyle::LowercaseH.is_canonical());
+        assert!(!HDPathComponentStyle::Global.is_canonical());
+    }
+
+    #[test]
+    fn global() {
+        assert_eq!(
+            format(HDPathComponentStyle::Global, PATH),
+            "m/2147483692/2147484670/0/3221225479"
+        );
+    }
+
+    #[test]
+    fn global_hex() {
+        assert_eq!(
+            format(HDPathComponentStyle::GlobalHex, PATH),
+            "m/0x8000002c/0x800003fe/0x0/0xc0000007"
+        );
+    }
+
+    #[test]
+    fn without_root() {
+        assert_eq!(
+            HDPath::from_str(PATH)
+                .unwrap()
+                .to_bip32_string_with_format(Sut::CANONICAL.without_root()),
+            "44H/1022H/0/7S"
+        );
+    }
+
+    #[test]
+    fn public_root() {
+        assert_eq!(
+            HDPath::from_str("M/0/5")
+                .unwrap()
+                .to_bip32_string_with_format(Sut::new(HDPathComponentStyle::GlobalHex)),
+            "M/0x0/0x5"
+        );
+    }
+
+    #[test]
+    fn component() {
+        let component = HDPathComponent::from_str("7S").unwrap();
+        assert_eq!(
+            component.to_bip32_string_with_format(Sut::new(HDPathComponentStyle::LowercaseH)),
+            "1073741831h"
+        );
+        assert_eq!(
+            SecurifiedU30::sample()
+                .to_bip32_string_with_format(Sut::new(HDPathComponentStyle::GlobalHex)),
+            "0xc000001e"
+        );
+        assert_eq!(
+            Unhardened::sample()
+                .to_bip32_string_with_format(Sut::new(HDPathComponentStyle::Apostrophe)),
+            "237"
+        );
+    }
+
+    #[test]
+    fn every_path_type() {
+        let format = Sut::new(HDPathComponentStyle::Apostrophe).without_root();
+        assert_eq!(
+            CAP26IdentityPath::sample().to_bip32_string_with_format(format),
+            "44'/1022'/1'/618'/1460'/0'"
+        );
+        assert_eq!(
+            BIP44LikePath::sample().to_bip32_string_with_format(format),
+            "44'/1022'/0'/0/0"
+        );
+        assert_eq!(
+            DerivationPath::account(CAP26AccountPath::sample()).to_bip32_string_with_format(format),
+            "44'/1022'/1'/525'/1460'/0'"
+        );
+        assert_eq!(
+            AccountPath::sample_other().to_bip32_string_with_format(format),
+            "44'/1022'/0'/0/0"
+        );
+        assert_eq!(
+            CAP26GetIDPath.to_bip32_string_with_format(format),
+            "44'/1022'/365'"
+        );
+        assert_eq!(
+            RelativeHDPath::from_str("0H/5S")
+                .unwrap()
+                .to_bip32_string_with_format(Sut::NON_CANONICAL),
+            "0'/5^"
+        );
+    }
+}
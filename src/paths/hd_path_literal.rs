@@ -0,0 +1,424 @@
+//! Compile time parsing of BIP32 path literals, used by the macros `hdpath!`,
+//! `cap26_account_path!`, `cap26_identity_path!` and `bip44_like_path!`.
+//!
+//! The parsing functions are `const fn`s which panic on invalid input, which
+//! the macros evaluate in a `const` item, turning any panic into a compile
+//! error. The ones the macros call are re-exported from `__private`.
+
+use crate::prelude::*;
+
+/// Creates an `HDPath` constant from a string literal, which is validated at
+/// compile time, e.g. `hdpath!("m/44H/1022H/1H/525H/1460H/0S")`.
+///
+/// The literal must start with the root `m/` or `M/`, and each component
+/// is an index optionally suffixed with `H`, `h` or `'` for hardened, or
+/// `S` or `^` for securified, or an index in the global key space. A path
+/// from the public root `M/` must only have unhardened components.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+/// use hdpath::hdpath;
+///
+/// assert_eq!(
+///     hdpath!("m/44H/1022H/1H/525H/1460H/0H"),
+///     CAP26AccountPath::sample().to_hd_path()
+/// );
+/// assert!(hdpath!("M/0/5").is_public());
+///
+/// const PATH: HDPath = hdpath!("m/44H/1022H");
+/// assert_eq!(PATH.depth(), 2);
+/// ```
+///
+/// Out of range indices do not compile
+/// ```compile_fail
+/// # use hdpath::hdpath;
+/// let _ = hdpath!("m/44H/1073741824H");
+/// ```
+///
+/// Nor do hardened components after the public root
+/// ```compile_fail
+/// # use hdpath::hdpath;
+/// let _ = hdpath!("M/44H");
+/// ```
+#[macro_export]
+macro_rules! hdpath {
+    ($path:literal) => {{
+        const LEN: usize = $crate::__private::hd_path_literal_len($path);
+        const PATH: $crate::HDPath = $crate::__private::hd_path_literal::<LEN>($path);
+        PATH
+    }};
+}
+
+/// Creates a `CAP26AccountPath` constant from a string literal, which is
+/// validated at compile time.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+/// use hdpath::cap26_account_path;
+///
+/// const PATH: CAP26AccountPath = cap26_account_path!("m/44H/1022H/1H/525H/1460H/0H");
+/// assert_eq!(PATH, CAP26AccountPath::sample());
+/// ```
+///
+/// An identity path is not an account path
+/// ```compile_fail
+/// # use hdpath::prelude::*;
+/// # use hdpath::cap26_account_path;
+/// const PATH: CAP26AccountPath = cap26_account_path!("m/44H/1022H/1H/618H/1460H/0H");
+/// ```
+///
+/// The last component must be hardened or securified
+/// ```compile_fail
+/// # use hdpath::prelude::*;
+/// # use hdpath::cap26_account_path;
+/// const PATH: CAP26AccountPath = cap26_account_path!("m/44H/1022H/1H/525H/1460H/0");
+/// ```
+#[macro_export]
+macro_rules! cap26_account_path {
+    ($path:literal) => {{
+        const PATH: $crate::CAP26AccountPath = $crate::__private::cap26_account_path_literal($path);
+        PATH
+    }};
+}
+
+/// Creates a `CAP26IdentityPath` constant from a string literal, which is
+/// validated at compile time.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+/// use hdpath::cap26_identity_path;
+///
+/// const PATH: CAP26IdentityPath = cap26_identity_path!("m/44H/1022H/1H/618H/1460H/0H");
+/// assert_eq!(PATH, CAP26IdentityPath::sample());
+/// ```
+#[macro_export]
+macro_rules! cap26_identity_path {
+    ($path:literal) => {{
+        const PATH: $crate::CAP26IdentityPath =
+            $crate::__private::cap26_identity_path_literal($path);
+        PATH
+    }};
+}
+
+/// Creates a `BIP44LikePath` constant from a string literal, which is
+/// validated at compile time.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+/// use hdpath::bip44_like_path;
+///
+/// const PATH: BIP44LikePath = bip44_like_path!("m/44H/1022H/0H/0/0");
+/// assert_eq!(PATH, BIP44LikePath::sample());
+/// ```
+///
+/// Only the last component may vary
+/// ```compile_fail
+/// # use hdpath::prelude::*;
+/// # use hdpath::bip44_like_path;
+/// const PATH: BIP44LikePath = bip44_like_path!("m/44H/1022H/1H/0/0");
+/// ```
+#[macro_export]
+macro_rules! bip44_like_path {
+    ($path:literal) => {{
+        const PATH: $crate::BIP44LikePath = $crate::__private::bip44_like_path_literal($path);
+        PATH
+    }};
+}
+
+/// The number of bytes of the root `m/` or `M/` of `path`, or of just `m`
+/// or `M`.
+const fn root_len(path: &[u8]) -> usize {
+    if path.is_empty() || !(path[0] == b'm' || path[0] == b'M') {
+        panic!("BIP32 path literal must start with the root `m/` or `M/`");
+    }
+    if path.len() == 1 {
+        return 1;
+    }
+    if path[1] != b'/' {
+        panic!("BIP32 path literal must start with the root `m/` or `M/`");
+    }
+    2
+}
+
+const fn hd_path_literal_root(path: &str) -> HDPathRoot {
+    let path = path.as_bytes();
+    root_len(path);
+    if path[0] == b'M' {
+        HDPathRoot::Public
+    } else {
+        HDPathRoot::Private
+    }
+}
+
+pub const fn hd_path_literal_len(path: &str) -> usize {
+    let path = path.as_bytes();
+    let start = root_len(path);
+    if start == path.len() && start == 1 {
+        return 0;
+    }
+    let mut len = 1;
+    let mut i = start;
+    while i < path.len() {
+        if path[i] == b'/' {
+            len += 1;
+        }
+        i += 1;
+    }
+    if len > HDPath::INLINE_DEPTH {
+        panic!("BIP32 path literal is deeper than `HDPath::INLINE_DEPTH`");
+    }
+    len
+}
+
+/// Parses the component `path[start..end]` into its index in the global key
+/// space.
+const fn parse_component(path: &[u8], start: usize, end: usize) -> u32 {
+    if start == end {
+        panic!("BIP32 path literal has an empty component");
+    }
+    let mut value: u64 = 0;
+    let mut i = start;
+    while i < end && path[i].is_ascii_digit() {
+        value = value * 10 + (path[i] - b'0') as u64;
+        if value > u32::MAX as u64 {
+            panic!("BIP32 path literal has an index out of range");
+        }
+        i += 1;
+    }
+    if i == start {
+        panic!("BIP32 path literal has a component not starting with an index");
+    }
+    let value = value as u32;
+    if i == end {
+        // Without a suffix an index above `U31_MAX` is a hardened or
+        // securified index in the global key space, like `HDPath::from_str`.
+        return value;
+    }
+    if i + 1 != end {
+        panic!("BIP32 path literal has an invalid suffix, expected H, h, ', S or ^");
+    }
+    match path[i] {
+        b'H' | b'h' | b'\'' => {
+            if value > U30_MAX {
+                panic!("BIP32 path literal has a hardened index out of range");
+            }
+            value + GLOBAL_OFFSET_HARDENED
+        }
+        b'S' | b'^' => {
+            if value > U30_MAX {
+                panic!("BIP32 path literal has a securified index out of range");
+            }
+            value + GLOBAL_OFFSET_HARDENED_SECURIFIED
+        }
+        _ => panic!("BIP32 path literal has an invalid suffix, expected H, h, ', S or ^"),
+    }
+}
+
+/// Parses the components of `path`, which must have exactly `N` components,
+/// into their indices in the global key space.
+const fn parse_global<const N: usize>(path: &str) -> [u32; N] {
+    if hd_path_literal_len(path) != N {
+        panic!("BIP32 path literal has the wrong number of components");
+    }
+    let is_public = matches!(hd_path_literal_root(path), HDPathRoot::Public);
+    let path = path.as_bytes();
+    let mut globals = [0; N];
+    let mut start = root_len(path);
+    let mut index = 0;
+    while index < N {
+        let mut end = start;
+        while end < path.len() && path[end] != b'/' {
+            end += 1;
+        }
+        globals[index] = parse_component(path, start, end);
+        if is_public && globals[index] >= GLOBAL_OFFSET_HARDENED {
+            panic!("BIP32 path literal has a hardened component after the public root `M`");
+        }
+        start = end + 1;
+        index += 1;
+    }
+    globals
+}
+
+/// `PURPOSE`, `COIN_TYPE` and others as a single component literal, e.g.
+/// `44H`.
+pub(crate) const fn hd_path_component_literal(component: &str) -> HDPathComponent {
+    let component = component.as_bytes();
+    HDPathComponent::from_global(parse_component(component, 0, component.len()))
+}
+
+pub const fn hd_path_literal<const N: usize>(path: &str) -> HDPath {
+    HDPath::from_global_indices(hd_path_literal_root(path), &parse_global::<N>(path))
+}
+
+/// The local index of the hardened unsecurified `global`.
+const fn hardened_local(global: u32, error: &str) -> u32 {
+    if global < GLOBAL_OFFSET_HARDENED || global >= GLOBAL_OFFSET_HARDENED_SECURIFIED {
+        panic!("{}", error);
+    }
+    global - GLOBAL_OFFSET_HARDENED
+}
+
+const fn check_purpose_and_coin_type(globals: &[u32]) {
+    if globals[0] != 44 + GLOBAL_OFFSET_HARDENED {
+        panic!("BIP32 path literal must have purpose 44H");
+    }
+    if globals[1] != 1022 + GLOBAL_OFFSET_HARDENED {
+        panic!("BIP32 path literal must have coin type 1022H");
+    }
+}
+
+const fn cap26_parts(
+    path: &str,
+    entity_kind: CAP26EntityKind,
+) -> (NetworkID, CAP26KeyKind, Hardened) {
+    let globals = parse_global::<6>(path);
+    check_purpose_and_coin_type(&globals);
+    let network_id = hardened_local(globals[2], "CAP26 network ID must be hardened");
+    if network_id > u8::MAX as u32 {
+        panic!("CAP26 path literal has an unknown network ID");
+    }
+    let Some(network_id) = NetworkID::from_repr(network_id as u8) else {
+        panic!("CAP26 path literal has an unknown network ID");
+    };
+    if hardened_local(globals[3], "CAP26 entity kind must be hardened") != entity_kind as u32 {
+        panic!("CAP26 path literal has the wrong entity kind");
+    }
+    let key_kind = hardened_local(globals[4], "CAP26 key kind must be hardened");
+    let Some(key_kind) = CAP26KeyKind::from_repr(key_kind) else {
+        panic!("CAP26 path literal has an unknown key kind");
+    };
+    let index = if globals[5] >= GLOBAL_OFFSET_HARDENED_SECURIFIED {
+        let Some(local) = U30::checked(globals[5] - GLOBAL_OFFSET_HARDENED_SECURIFIED) else {
+            unreachable!()
+        };
+        Hardened::Securified(SecurifiedU30::new(local))
+    } else {
+        let local = hardened_local(globals[5], "CAP26 index must be hardened or securified");
+        let Some(local) = U30::checked(local) else {
+            unreachable!()
+        };
+        Hardened::Unsecurified(UnsecurifiedHardened::new(local))
+    };
+    (network_id, key_kind, index)
+}
+
+pub const fn cap26_account_path_literal(path: &str) -> CAP26AccountPath {
+    let (network_id, key_kind, index) = cap26_parts(path, CAP26EntityKind::Account);
+    CAP26AccountPath::from_parts(network_id, key_kind, index)
+}
+
+pub const fn cap26_identity_path_literal(path: &str) -> CAP26IdentityPath {
+    let (network_id, key_kind, index) = cap26_parts(path, CAP26EntityKind::Identity);
+    CAP26IdentityPath::from_parts(network_id, key_kind, index)
+}
+
+pub const fn bip44_like_path_literal(path: &str) -> BIP44LikePath {
+    let globals = parse_global::<5>(path);
+    check_purpose_and_coin_type(&globals);
+    if globals[2] != GLOBAL_OFFSET_HARDENED {
+        panic!("BIP44 like path literal must have account 0H");
+    }
+    if globals[3] != 0 {
+        panic!("BIP44 like path literal must have change 0");
+    }
+    BIP44LikePath::new(HDPathComponent::from_global(globals[4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hdpath() {
+        assert_eq!(
+            crate::hdpath!("m/44H/1022H/1H/525H/1460H/0S"),
+            HDPath::from_str("m/44H/1022H/1H/525H/1460H/0S").unwrap()
+        );
+    }
+
+    #[test]
+    fn hdpath_non_canonical() {
+        assert_eq!(
+            crate::hdpath!("m/44'/1022h/0/5^"),
+            HDPath::from_str("m/44H/1022H/0/5S").unwrap()
+        );
+    }
+
+    #[test]
+    fn hdpath_root() {
+        assert_eq!(crate::hdpath!("m"), HDPath::from_str("m").unwrap());
+        assert_eq!(crate::hdpath!("M"), HDPath::from_str("M").unwrap());
+    }
+
+    #[test]
+    fn hdpath_public() {
+        assert_eq!(
+            crate::hdpath!("M/0/2147483647"),
+            HDPath::from_str("M/0/2147483647").unwrap()
+        );
+    }
+
+    #[test]
+    fn hdpath_max() {
+        assert_eq!(
+            crate::hdpath!("m/1073741823H/1073741823S"),
+            HDPath::from_str("m/1073741823H/1073741823S").unwrap()
+        );
+    }
+
+    #[test]
+    fn hdpath_global() {
+        assert_eq!(crate::hdpath!("m/2147483692"), crate::hdpath!("m/44H"));
+        assert_eq!(
+            crate::hdpath!("m/2147484670/3221225472/4294967295"),
+            HDPath::from_str("m/1022H/0S/1073741823S").unwrap()
+        );
+    }
+
+    #[test]
+    fn cap26_account_path() {
+        const PATH: CAP26AccountPath = crate::cap26_account_path!("m/44H/1022H/1H/525H/1460H/0H");
+        assert_eq!(PATH, CAP26AccountPath::sample());
+    }
+
+    #[test]
+    fn cap26_account_path_securified() {
+        assert_eq!(
+            crate::cap26_account_path!("m/44H/1022H/2H/525H/1678H/5S"),
+            CAP26AccountPath::from_str("m/44H/1022H/2H/525H/1678H/5S").unwrap()
+        );
+    }
+
+    #[test]
+    fn cap26_identity_path() {
+        assert_eq!(
+            crate::cap26_identity_path!("m/44H/1022H/1H/618H/1460H/0H"),
+            CAP26IdentityPath::sample()
+        );
+    }
+
+    #[test]
+    fn bip44_like_path() {
+        assert_eq!(
+            crate::bip44_like_path!("m/44H/1022H/0H/0/1H"),
+            BIP44LikePath::sample_other()
+        );
+    }
+
+    #[test]
+    fn component_literal() {
+        assert_eq!(
+            hd_path_component_literal("5S"),
+            HDPathComponent::from_str("5S").unwrap()
+        );
+    }
+}
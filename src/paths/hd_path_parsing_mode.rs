@@ -0,0 +1,14 @@
+/// How strictly a BIP32 path string is parsed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum HDPathParsingMode {
+    /// Accepts a root of `m/`, `M/`, `/` or no root at all, skips empty
+    /// segments and accepts any number of components.
+    #[default]
+    Lenient,
+
+    /// Requires the root `m` or `M` and rejects empty segments, leading
+    /// zeros, whitespace, mixing suffix styles within one path, i.e. `H` and
+    /// `S`, `'` and `^`, `h` or global indices, and paths deeper than
+    /// `HDPath::MAX_DEPTH`.
+    Strict,
+}
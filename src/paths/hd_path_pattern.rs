@@ -0,0 +1,597 @@
+use alloc::collections::BTreeSet;
+use core::ops::Bound;
+
+use itertools::Itertools;
+
+use crate::prelude::*;
+
+/// A segment of an `HDPathPattern`, matching one or many `HDPathComponent`s,
+/// all of which are in the same `KeySpace`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HDPathPatternComponent {
+    /// Matches exactly one component, e.g. `44H`.
+    Exact(HDPathComponent),
+
+    /// Matches every component in a key space, e.g. `*H`.
+    Wildcard(KeySpace),
+
+    /// Matches components from `start` up to `end`, e.g. `0H..100H` (`end`
+    /// excluded) or `0H..=100H` (`end` included).
+    Range {
+        start: HDPathComponent,
+        end: HDPathComponent,
+        is_end_inclusive: bool,
+    },
+
+    /// Matches any of the members, e.g. `{0,5,9}S`, which when parsed is
+    /// never empty.
+    Set(BTreeSet<HDPathComponent>),
+}
+
+const WILDCARD: &str = "*";
+const RANGE_SEPARATOR: &str = "..";
+const RANGE_INCLUSIVE_END: &str = "=";
+const SET_START: &str = "{";
+const SET_END: &str = "}";
+const SET_SEPARATOR: &str = ",";
+
+/// Splits `s` into its local index part and the `KeySpace` indicated by its
+/// suffix, either canonical, non-canonical or lenient, e.g. lowercase `h`.
+fn split_key_space_suffix(s: &str) -> (&str, KeySpace) {
+    let hardened = UnsecurifiedHardened::ACCEPTABLE_SUFFIXES
+        .iter()
+        .chain(UnsecurifiedHardened::LENIENT_SUFFIXES)
+        .map(|suffix| (suffix, KeySpace::Unsecurified { is_hardened: true }));
+    let securified = SecurifiedU30::ACCEPTABLE_SUFFIXES
+        .iter()
+        .chain(SecurifiedU30::LENIENT_SUFFIXES)
+        .map(|suffix| (suffix, KeySpace::Securified));
+    hardened
+        .chain(securified)
+        .find_map(|(suffix, key_space)| s.strip_suffix(suffix).map(|rest| (rest, key_space)))
+        .unwrap_or((s, KeySpace::Unsecurified { is_hardened: false }))
+}
+
+fn key_space_suffix(key_space: KeySpace, canonical: bool) -> &'static str {
+    match (key_space, canonical) {
+        (KeySpace::Securified, true) => SecurifiedU30::CANONICAL_SUFFIX,
+        (KeySpace::Securified, false) => SecurifiedU30::NON_CANONICAL_SUFFIXES,
+        (KeySpace::Unsecurified { is_hardened: true }, true) => {
+            UnsecurifiedHardened::CANONICAL_SUFFIX
+        }
+        (KeySpace::Unsecurified { is_hardened: true }, false) => {
+            UnsecurifiedHardened::NON_CANONICAL_SUFFIXES
+        }
+        (KeySpace::Unsecurified { is_hardened: false }, true) => Unhardened::CANONICAL_SUFFIX,
+        (KeySpace::Unsecurified { is_hardened: false }, false) => {
+            Unhardened::NON_CANONICAL_SUFFIXES
+        }
+    }
+}
+
+impl HDPathPatternComponent {
+    /// The key space of all components matched by this pattern component,
+    /// `None` only for an empty `Set`.
+    pub fn key_space(&self) -> Option<KeySpace> {
+        match self {
+            Self::Exact(component) => Some(component.key_space()),
+            Self::Wildcard(key_space) => Some(*key_space),
+            Self::Range { start, .. } => Some(start.key_space()),
+            Self::Set(members) => members.first().map(|c| c.key_space()),
+        }
+    }
+
+    pub fn matches(&self, component: &HDPathComponent) -> bool {
+        match self {
+            Self::Exact(exact) => exact == component,
+            Self::Wildcard(key_space) => component.key_space() == *key_space,
+            Self::Range {
+                start,
+                end,
+                is_end_inclusive,
+            } => {
+                let global = component.map_to_global_key_space();
+                let start = start.map_to_global_key_space();
+                let end = end.map_to_global_key_space();
+                global >= start && (global < end || (*is_end_inclusive && global == end))
+            }
+            Self::Set(members) => members.contains(component),
+        }
+    }
+
+    /// The first component matched, if any.
+    fn first(&self) -> Option<HDPathComponent> {
+        match self {
+            Self::Exact(exact) => Some(*exact),
+            Self::Wildcard(key_space) => Some(
+                HDPathComponent::from_local_key_space(0, *key_space)
+                    .expect("Zero is valid in every key space"),
+            ),
+            Self::Range { start, .. } => Some(*start).filter(|c| self.matches(c)),
+            Self::Set(members) => members.first().copied(),
+        }
+    }
+
+    /// The component matched after `component`, if any, never crossing into
+    /// another key space.
+    fn next_after(&self, component: &HDPathComponent) -> Option<HDPathComponent> {
+        match self {
+            Self::Exact(_) => None,
+            Self::Wildcard(_) => component.checked_add_one_to_global().ok(),
+            Self::Range { .. } => component
+                .checked_add_one_to_global()
+                .ok()
+                .filter(|c| self.matches(c)),
+            Self::Set(members) => members
+                .range((Bound::Excluded(component), Bound::Unbounded))
+                .next()
+                .copied(),
+        }
+    }
+
+    fn to_string_with(&self, canonical: bool) -> String {
+        let component = |c: &HDPathComponent| {
+            if canonical {
+                c.to_string()
+            } else {
+                format!("{:?}", c)
+            }
+        };
+        let suffix = self
+            .key_space()
+            .map(|key_space| key_space_suffix(key_space, canonical))
+            .unwrap_or_default();
+        match self {
+            Self::Exact(exact) => component(exact),
+            Self::Wildcard(_) => format!("{}{}", WILDCARD, suffix),
+            Self::Range {
+                start,
+                end,
+                is_end_inclusive,
+            } => format!(
+                "{}{}{}{}",
+                component(start),
+                RANGE_SEPARATOR,
+                if *is_end_inclusive {
+                    RANGE_INCLUSIVE_END
+                } else {
+                    ""
+                },
+                component(end)
+            ),
+            Self::Set(members) => format!(
+                "{}{}{}{}",
+                SET_START,
+                members
+                    .iter()
+                    .map(|c| u32::from(c.index_in_local_key_space()))
+                    .join(SET_SEPARATOR),
+                SET_END,
+                suffix
+            ),
+        }
+    }
+}
+
+impl FromBIP32Str for HDPathPatternComponent {
+    fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
+        let s = s.as_ref();
+        if let Some((start, end)) = s.split_once(RANGE_SEPARATOR) {
+            let (end, is_end_inclusive) = match end.strip_prefix(RANGE_INCLUSIVE_END) {
+                Some(end) => (end, true),
+                None => (end, false),
+            };
+            let start = HDPathComponent::from_str(start)?;
+            let end = HDPathComponent::from_str(end)?;
+            if start.key_space() != end.key_space() {
+                return Err(CommonError::HDPathPatternRangeSpansMultipleKeySpaces);
+            }
+            return Ok(Self::Range {
+                start,
+                end,
+                is_end_inclusive,
+            });
+        }
+
+        let (rest, key_space) = split_key_space_suffix(s);
+        if rest == WILDCARD {
+            return Ok(Self::Wildcard(key_space));
+        }
+        if let Some(members) = rest
+            .strip_prefix(SET_START)
+            .and_then(|r| r.strip_suffix(SET_END))
+        {
+            let members = members
+                .split(SET_SEPARATOR)
+                .map(|m| {
+                    m.parse::<u32>()
+                        .map_err(|_| CommonError::NonU32Str)
+                        .and_then(|local| HDPathComponent::from_local_key_space(local, key_space))
+                })
+                .collect::<Result<BTreeSet<_>>>()?;
+            return Ok(Self::Set(members));
+        }
+
+        HDPathComponent::from_str(s).map(Self::Exact)
+    }
+}
+
+/// A pattern of BIP32 paths, made up of `HDPathPatternComponent`s, which
+/// can tell if an `HDPath` matches it and can lazily be expanded into all
+/// the `HDPath`s it matches.
+///
+/// # Examples
+/// ```
+/// extern crate hdpath;
+/// use hdpath::prelude::*;
+///
+/// let pattern = HDPathPattern::from_bip32_string("m/44H/1022H/1H/525H/1460H/{0,5}H").unwrap();
+/// assert_eq!(
+///     pattern.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+///     vec!["m/44H/1022H/1H/525H/1460H/0H", "m/44H/1022H/1H/525H/1460H/5H"]
+/// );
+/// assert!(pattern.matches(CAP26AccountPath::sample()));
+/// ```
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    MoreDebug,
+    derive_more::Display,
+    SerializeDisplay,
+    DeserializeFromStr,
+)]
+#[display("{}", self.to_bip32_string())]
+#[debug("{}", self.to_bip32_string_debug())]
+pub struct HDPathPattern(Vec<HDPathPatternComponent>);
+
+impl HDPathPattern {
+    pub fn new(components: Vec<HDPathPatternComponent>) -> Self {
+        Self(components)
+    }
+
+    pub fn components(&self) -> &[HDPathPatternComponent] {
+        &self.0
+    }
+
+    /// Whether `path` is from the private root `m`, has as many components
+    /// as this pattern and each of them is matched by the corresponding
+    /// pattern component.
+    pub fn matches(&self, path: impl Into<HDPath>) -> bool {
+        let path = path.into();
+        !path.is_public()
+            && path.depth() == self.0.len()
+            && self
+                .0
+                .iter()
+                .zip(path.components())
+                .all(|(pattern, component)| pattern.matches(&component))
+    }
+
+    /// Lazily expands this pattern into every `HDPath` matching it, in
+    /// ascending order.
+    pub fn iter(&self) -> HDPathPatternIterator<'_> {
+        HDPathPatternIterator {
+            pattern: self,
+            next: self
+                .0
+                .iter()
+                .map(HDPathPatternComponent::first)
+                .collect::<Option<Vec<_>>>(),
+        }
+    }
+
+    fn to_string_with(&self, canonical: bool) -> String {
+        let head = "m".to_owned();
+        let mut path = vec![head];
+        path.extend(self.0.iter().map(|c| c.to_string_with(canonical)));
+        path.into_iter().join(HDPath::SEPARATOR)
+    }
+}
+
+impl<'a> IntoIterator for &'a HDPathPattern {
+    type Item = HDPath;
+    type IntoIter = HDPathPatternIterator<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over every `HDPath` matching an `HDPathPattern`, see
+/// `HDPathPattern::iter`.
+pub struct HDPathPatternIterator<'a> {
+    pattern: &'a HDPathPattern,
+    next: Option<Vec<HDPathComponent>>,
+}
+
+impl Iterator for HDPathPatternIterator<'_> {
+    type Item = HDPath;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        // Advance like an odometer, the last component changing fastest.
+        for (index, pattern) in self.pattern.0.iter().enumerate().rev() {
+            if let Some(component) = pattern.next_after(&next[index]) {
+                next[index] = component;
+                self.next = Some(next);
+                break;
+            }
+            next[index] = pattern.first().expect("Was non-empty");
+        }
+        Some(HDPath::new(current))
+    }
+}
+
+impl ToBIP32Str for HDPathPattern {
+    fn to_bip32_string(&self) -> String {
+        self.to_string_with(true)
+    }
+    fn to_bip32_string_debug(&self) -> String {
+        self.to_string_with(false)
+    }
+}
+
+impl FromBIP32Str for HDPathPattern {
+    fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
+        let input = s.as_ref();
+        let mut components = Vec::new();
+        let mut spans = Vec::new();
+        for (span, segment) in HDPath::segments_with_spans(input) {
+            spans.push(span);
+            let component = HDPathPatternComponent::from_bip32_string(segment).map_err(|e| {
+                InvalidHDPathComponent::new(
+                    components.len(),
+                    "an index, `*`, range `a..b` or set `{a,b}`, optionally suffixed with H, ', S or ^",
+                    e,
+                )
+                .located_in(input, &spans)
+            })?;
+            components.push(component);
+        }
+        Ok(Self(components))
+    }
+}
+
+impl FromStr for HDPathPattern {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bip32_string(s)
+    }
+}
+
+impl From<HDPath> for HDPathPattern {
+    fn from(path: HDPath) -> Self {
+        Self(
+            path.components()
+                .map(HDPathPatternComponent::Exact)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = HDPathPattern;
+
+    fn expand(pattern: &str) -> Vec<String> {
+        Sut::from_str(pattern)
+            .unwrap()
+            .iter()
+            .map(|p| p.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for s in [
+            "m/44H/1022H/1H/525H/1460H/*H",
+            "m/44H/1022H/1H/525H/1460H/0H..100H",
+            "m/44H/1022H/1H/525H/1460H/0H..=100H",
+            "m/44H/1022H/1H/525H/1460H/{0,5,9}S",
+            "m/44H/1022H/0H/0/*",
+        ] {
+            assert_eq!(Sut::from_str(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Sut::from_str("m/44H/*S/{1,2}H/3..5").unwrap()),
+            "m/44'/*^/{1,2}'/3..5"
+        );
+    }
+
+    #[test]
+    fn from_str_non_canonical() {
+        assert_eq!(
+            Sut::from_str("m/44'/1022'/*^/{0,5}'").unwrap(),
+            Sut::from_str("m/44H/1022H/*S/{0,5}H").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_lowercase_h() {
+        assert_eq!(
+            Sut::from_str("m/44h/1022h/*h").unwrap(),
+            Sut::from_str("m/44H/1022H/*H").unwrap()
+        );
+        for (lowercase, uppercase) in [
+            (
+                "m/44h/1022h/1h/525h/1460h/{0,5}h",
+                "m/44H/1022H/1H/525H/1460H/{0,5}H",
+            ),
+            ("m/44h/1022h/0h..=2h", "m/44H/1022H/0H..=2H"),
+        ] {
+            assert_eq!(
+                Sut::from_str(lowercase).unwrap(),
+                Sut::from_str(uppercase).unwrap()
+            );
+            assert_eq!(expand(lowercase), expand(uppercase));
+        }
+        assert!(Sut::from_str("m/44h/1022h/*h")
+            .unwrap()
+            .matches(HDPath::from_str("m/44h/1022h/1h").unwrap()));
+    }
+
+    #[test]
+    fn set_is_sorted_and_deduplicated() {
+        assert_eq!(
+            Sut::from_str("m/{9,0,5,0}H").unwrap().to_string(),
+            "m/{0,5,9}H"
+        );
+    }
+
+    #[test]
+    fn expand_exact() {
+        assert_eq!(
+            expand("m/44H/1022H/1H/525H/1460H/0H"),
+            vec!["m/44H/1022H/1H/525H/1460H/0H"]
+        );
+    }
+
+    #[test]
+    fn expand_set() {
+        assert_eq!(
+            expand("m/44H/1022H/1H/525H/1460H/{0,5,9}S"),
+            vec![
+                "m/44H/1022H/1H/525H/1460H/0S",
+                "m/44H/1022H/1H/525H/1460H/5S",
+                "m/44H/1022H/1H/525H/1460H/9S"
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_range_exclusive() {
+        assert_eq!(
+            expand("m/44H/1022H/0H/0/2..4"),
+            vec!["m/44H/1022H/0H/0/2", "m/44H/1022H/0H/0/3"]
+        );
+    }
+
+    #[test]
+    fn expand_range_inclusive() {
+        assert_eq!(
+            expand("m/44H/1022H/0H/0/2H..=4H"),
+            vec![
+                "m/44H/1022H/0H/0/2H",
+                "m/44H/1022H/0H/0/3H",
+                "m/44H/1022H/0H/0/4H"
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_range_empty() {
+        assert!(expand("m/44H/5H..5H").is_empty());
+        assert!(expand("m/44H/7H..5H").is_empty());
+    }
+
+    #[test]
+    fn expand_many_segments_odometer_order() {
+        assert_eq!(
+            expand("m/{1,2}H/0..2"),
+            vec!["m/1H/0", "m/1H/1", "m/2H/0", "m/2H/1"]
+        );
+    }
+
+    #[test]
+    fn expand_wildcard_is_lazy() {
+        let sut = Sut::from_str("m/44H/1022H/1H/525H/1460H/*H").unwrap();
+        assert_eq!(
+            sut.iter()
+                .take(3)
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "m/44H/1022H/1H/525H/1460H/0H",
+                "m/44H/1022H/1H/525H/1460H/1H",
+                "m/44H/1022H/1H/525H/1460H/2H"
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_does_not_cross_key_space() {
+        let sut = Sut::from_str("m/1073741822H..=1073741823H").unwrap();
+        assert_eq!(
+            sut.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+            vec!["m/1073741822H", "m/1073741823H"]
+        );
+    }
+
+    #[test]
+    fn expand_range_across_key_spaces_fails() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("m/44H/0..5H") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 1);
+        assert_eq!(
+            error.reason,
+            CommonError::HDPathPatternRangeSpansMultipleKeySpaces
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!(Sut::from_str("m/44H/{}H").is_err());
+        assert!(Sut::from_str("m/44H/{1,x}H").is_err());
+        assert!(Sut::from_str("m/44H/**").is_err());
+        assert!(Sut::from_str("m/44H/{1073741824}S").is_err());
+    }
+
+    #[test]
+    fn matches_hd_path() {
+        let sut = Sut::from_str("m/44H/1022H/1H/525H/1460H/*H").unwrap();
+        assert!(sut.matches(CAP26AccountPath::sample()));
+        assert!(sut.matches(CAP26AccountPath::sample_other()));
+        assert!(!sut.matches(CAP26IdentityPath::sample()));
+        assert!(!sut.matches(HDPath::from_str("m/44H/1022H/1H/525H/1460H/0S").unwrap()));
+        assert!(!sut.matches(HDPath::from_str("m/44H/1022H/1H/525H/1460H").unwrap()));
+    }
+
+    #[test]
+    fn matches_derivation_path() {
+        let sut = Sut::from_str("m/44H/1022H/0H/0/{0,1}").unwrap();
+        assert!(sut.matches(DerivationPath::bip44like(BIP44LikePath::sample())));
+        assert!(!sut.matches(DerivationPath::account(CAP26AccountPath::sample())));
+    }
+
+    #[test]
+    fn matches_range() {
+        let sut = Sut::from_str("m/2S..=4S").unwrap();
+        assert!(!sut.matches(HDPath::from_str("m/1S").unwrap()));
+        assert!(sut.matches(HDPath::from_str("m/2S").unwrap()));
+        assert!(sut.matches(HDPath::from_str("m/4S").unwrap()));
+        assert!(!sut.matches(HDPath::from_str("m/4H").unwrap()));
+        assert!(!sut.matches(HDPath::from_str("m/5S").unwrap()));
+    }
+
+    #[test]
+    fn every_expanded_path_matches() {
+        let sut = Sut::from_str("m/44H/{0,3}H/1..=3/*S").unwrap();
+        assert!(sut.iter().take(20).all(|p| sut.matches(p)));
+    }
+
+    #[test]
+    fn from_hd_path() {
+        let path = CAP26AccountPath::sample().to_hd_path();
+        let sut = Sut::from(path.clone());
+        assert_eq!(sut.iter().collect::<Vec<_>>(), vec![path]);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::from_str("m/44H/1022H/1H/525H/1460H/{0,5,9}S").unwrap();
+        assert_json_value_eq_after_roundtrip(&sut, json!("m/44H/1022H/1H/525H/1460H/{0,5,9}S"));
+        assert_json_roundtrip(&sut);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::prelude::*;
+
+/// The master key an `HDPath` derives from, written as the first segment of
+/// the path, `m` or `M`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Display, Debug)]
+pub enum HDPathRoot {
+    /// `m`, the master private key, from which any component can be derived.
+    #[default]
+    #[display("m")]
+    Private,
+
+    /// `M`, the master public key, from which only unhardened components can
+    /// be derived, without access to the seed.
+    #[display("M")]
+    Public,
+}
+
+impl HDPathRoot {
+    pub fn is_public(&self) -> bool {
+        *self == Self::Public
+    }
+
+    /// The root of the BIP32 path `input`, `Public` if it starts with `M`,
+    /// otherwise `Private`, also if `input` lacks a root.
+    pub(crate) fn of(input: &str) -> Self {
+        if input == "M" || input.starts_with("M/") {
+            Self::Public
+        } else {
+            Self::Private
+        }
+    }
+
+    /// Whether `component` can be derived from a key derived from this root,
+    /// i.e. any component if `Private`, only unhardened if `Public`.
+    pub(crate) fn can_derive(&self, component: &HDPathComponent) -> bool {
+        !self.is_public() || component.is_unhardened()
+    }
+
+    /// Fails with the first of `components` which cannot be derived from this
+    /// root, i.e. the first hardened or securified component if `Public`.
+    pub(crate) fn validate(
+        &self,
+        components: &[HDPathComponent],
+    ) -> Result<(), InvalidHDPathComponent> {
+        match components.iter().position(|c| !self.can_derive(c)) {
+            Some(index) => Err(InvalidHDPathComponent::new(
+                index,
+                "an unhardened component after public root `M`",
+                CommonError::HardenedComponentInPublicDerivationPath,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = HDPathRoot;
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::Private.to_string(), "m");
+        assert_eq!(Sut::Public.to_string(), "M");
+    }
+
+    #[test]
+    fn of() {
+        assert_eq!(Sut::of("m/44H"), Sut::Private);
+        assert_eq!(Sut::of("44H"), Sut::Private);
+        assert_eq!(Sut::of("M/0"), Sut::Public);
+        assert_eq!(Sut::of("M"), Sut::Public);
+    }
+
+    #[test]
+    fn validate_private_accepts_hardened() {
+        assert!(Sut::Private
+            .validate(&[HDPathComponent::sample_other()])
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_public_rejects_hardened_or_securified() {
+        let unhardened = HDPathComponent::from_str("0").unwrap();
+        for hardened in ["5H", "5S"] {
+            let hardened = HDPathComponent::from_str(hardened).unwrap();
+            let error = Sut::Public.validate(&[unhardened, hardened]).unwrap_err();
+            assert_eq!(error.index, 1);
+            assert_eq!(
+                error.reason,
+                CommonError::HardenedComponentInPublicDerivationPath
+            );
+        }
+    }
+}
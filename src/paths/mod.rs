@@ -1,44 +1,46 @@
 mod account_path;
+mod bip32_path_parse_error;
 mod bip44_like_path;
 mod cap26;
 mod derivation_path;
 mod hd_path;
+mod hd_path_format;
+pub(crate) mod hd_path_literal;
+mod hd_path_parsing_mode;
+mod hd_path_pattern;
+mod hd_path_root;
+mod path_union;
+mod path_union_parse_error;
+mod public_hd_path;
+mod relative_hd_path;
+mod slip10_curve;
 mod traits;
 
 pub use account_path::*;
+pub use bip32_path_parse_error::*;
 pub use bip44_like_path::*;
 pub use cap26::*;
 pub use derivation_path::*;
 pub use hd_path::*;
+pub use hd_path_format::*;
+pub use hd_path_parsing_mode::*;
+pub use hd_path_pattern::*;
+pub use hd_path_root::*;
+pub(crate) use path_union::*;
+pub use path_union_parse_error::*;
+pub use public_hd_path::*;
+pub use relative_hd_path::*;
+pub use slip10_curve::*;
 pub use traits::*;
 
 use crate::prelude::*;
+use hd_path_literal::hd_path_component_literal;
 
-/// # Safety
-/// Unsafe, does not validate the value to be small enough.
-///
-/// Only use this for tests and constants.
-const unsafe fn hard(value: u16) -> HDPathComponent {
-    unsafe {
-        HDPathComponent::Unsecurified(Unsecurified::Hardened(UnsecurifiedHardened::new(U30::new(
-            value,
-        ))))
-    }
-}
-
-/// # Safety
-/// Unsafe, does not validate the value to be small enough.
-///
-/// Only use this for tests and constants.
-const unsafe fn unhard(value: u16) -> HDPathComponent {
-    HDPathComponent::Unsecurified(Unsecurified::Unhardened(Unhardened::new(U31::new(value))))
-}
-
-pub(super) const PURPOSE: HDPathComponent = unsafe { hard(44) };
-pub(super) const GET_ID_LAST: HDPathComponent = unsafe { hard(365) };
-pub(super) const COIN_TYPE: HDPathComponent = unsafe { hard(1022) };
-pub(super) const BIP44_ACCOUNT: HDPathComponent = unsafe { hard(0) };
-pub(super) const BIP44_CHANGE: HDPathComponent = unsafe { unhard(0) };
+pub(super) const PURPOSE: HDPathComponent = hd_path_component_literal("44H");
+pub(super) const GET_ID_LAST: HDPathComponent = hd_path_component_literal("365H");
+pub(super) const COIN_TYPE: HDPathComponent = hd_path_component_literal("1022H");
+pub(super) const BIP44_ACCOUNT: HDPathComponent = hd_path_component_literal("0H");
+pub(super) const BIP44_CHANGE: HDPathComponent = hd_path_component_literal("0");
 
 pub(super) fn cap26(
     network_id: NetworkID,
@@ -46,22 +48,26 @@ pub(super) fn cap26(
     key_kind: CAP26KeyKind,
     index: Hardened,
 ) -> HDPath {
-    let mut path: [HDPathComponent; 6] = [PURPOSE; 6];
-    path[1] = COIN_TYPE;
-    path[2] = HDPathComponent::from(network_id);
-    path[3] = HDPathComponent::from(entity_kind);
-    path[4] = HDPathComponent::from(key_kind);
-    path[5] = HDPathComponent::from(index);
-    HDPath::new(Vec::from_iter(path))
+    HDPath::from_global_indices(
+        HDPathRoot::Private,
+        &[
+            PURPOSE,
+            COIN_TYPE,
+            HDPathComponent::from(network_id),
+            HDPathComponent::from(entity_kind),
+            HDPathComponent::from(key_kind),
+            HDPathComponent::from(index),
+        ]
+        .map(|c| c.map_to_global_key_space()),
+    )
 }
 
 pub(super) fn bip44(index: HDPathComponent) -> HDPath {
-    let mut path: [HDPathComponent; 5] = [PURPOSE; 5];
-    path[1] = COIN_TYPE;
-    path[2] = BIP44_ACCOUNT;
-    path[3] = BIP44_CHANGE;
-    path[4] = index;
-    HDPath::new(Vec::from_iter(path))
+    HDPath::from_global_indices(
+        HDPathRoot::Private,
+        &[PURPOSE, COIN_TYPE, BIP44_ACCOUNT, BIP44_CHANGE, index]
+            .map(|c| c.map_to_global_key_space()),
+    )
 }
 
 #[cfg(test)]
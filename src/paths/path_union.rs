@@ -0,0 +1,137 @@
+macro_rules! path_union {
+    (
+        $(
+            #[doc = $expr: expr]
+        )*
+        $union_name: ident,
+        $(
+            $variant_name: ident,
+            $variant_type: ty
+        )+
+    ) => {
+        paste::paste! {
+            $(
+                #[doc = $expr]
+            )*
+            #[derive(
+                Clone,
+                PartialEq,
+                EnumAsInner,
+                Eq,
+                Hash,
+                PartialOrd,
+                Ord,
+                SerializeDisplay,
+                DeserializeFromStr,
+                MoreDebug,
+                derive_more::Display,
+            )]
+            pub enum $union_name {
+                $(
+                    #[display("{value}")]
+                    #[debug("{:?}", value)]
+                    $variant_name { value: $variant_type },
+                )+
+            }
+
+            impl $union_name {
+                $(
+                    pub fn [< $variant_name:lower >](path: $variant_type) -> Self {
+                        Self::$variant_name { value: path }
+                    }
+                )+
+            }
+
+            impl FromStr for $union_name {
+                type Err = CommonError;
+                fn from_str(s: &str) -> Result<Self> {
+                    Self::from_bip32_string(s)
+                }
+            }
+
+            impl $union_name {
+                pub fn to_hd_path(&self) -> HDPath {
+                    match self {
+                        $(
+                            Self::$variant_name { value } => value.to_hd_path(),
+                        )+
+                    }
+                }
+            }
+
+            impl HasSLIP10Curve for $union_name {
+                fn curve(&self) -> SLIP10Curve {
+                    match self {
+                        $(
+                            Self::$variant_name { value } => value.curve(),
+                        )+
+                    }
+                }
+            }
+
+            $(
+                impl TryFrom<$union_name> for $variant_type {
+                    type Error = CommonError;
+                    fn try_from(path: $union_name) -> Result<Self> {
+                        match path {
+                            $union_name::$variant_name { value } => Ok(value),
+                            other => Self::try_from(other.to_hd_path()),
+                        }
+                    }
+                }
+            )+
+
+            impl From<$union_name> for HDPath {
+                fn from(path: $union_name) -> Self {
+                    path.to_hd_path()
+                }
+            }
+
+            impl ToBIP32Str for $union_name {
+                fn to_bip32_string(&self) -> String {
+                    self.to_hd_path().to_bip32_string()
+                }
+                fn to_bip32_string_debug(&self) -> String {
+                    self.to_hd_path().to_bip32_string_debug()
+                }
+            }
+
+            impl ToBIP32StrWithFormat for $union_name {
+                fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+                    self.to_hd_path().to_bip32_string_with_format(format)
+                }
+            }
+
+            impl FromBIP32Str for $union_name {
+                fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
+                    Self::from_bip32_string_with_mode(s, HDPathParsingMode::Lenient)
+                }
+            }
+
+            impl $union_name {
+                pub fn from_bip32_string_with_mode(
+                    s: impl AsRef<str>,
+                    mode: HDPathParsingMode,
+                ) -> Result<Self> {
+                    let s = s.as_ref();
+                    let mut rejections = Vec::new();
+                    $(
+                        match $variant_type::from_bip32_string_with_mode(s, mode) {
+                            Ok(path) => return Ok(Self::[< $variant_name:lower >](path)),
+                            Err(reason) => rejections.push(PathUnionVariantRejection::new(
+                                stringify!($variant_name),
+                                reason,
+                            )),
+                        }
+                    )+
+                    Err(PathUnionParseError::new(stringify!($union_name), s, rejections).into())
+                }
+            }
+
+        }
+    };
+
+
+}
+
+pub(crate) use path_union;
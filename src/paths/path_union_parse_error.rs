@@ -0,0 +1,162 @@
+use crate::prelude::*;
+
+/// A variant of a path union, e.g. `Account` of `DerivationPath`, which
+/// rejected an input string, and the `reason` why.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, derive_more::Display)]
+#[display("{variant}: {reason}")]
+pub struct PathUnionVariantRejection {
+    pub variant: String,
+    pub reason: CommonError,
+}
+
+impl PathUnionVariantRejection {
+    pub fn new(variant: impl Into<String>, reason: CommonError) -> Self {
+        Self {
+            variant: variant.into(),
+            reason,
+        }
+    }
+
+    /// The index of the component at which the variant rejected the input,
+    /// i.e. the number of leading components which were valid for it, `0` if
+    /// the reason is not positional.
+    pub fn component_index(&self) -> usize {
+        match &self.reason {
+            CommonError::InvalidBIP32Path(e) => e.component_index,
+            _ => 0,
+        }
+    }
+}
+
+/// Error produced when an input string could not be parsed as any of the
+/// variants of a path union, e.g. `DerivationPath`, listing the `rejections`
+/// of each variant, in declaration order.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PathUnionParseError {
+    pub union: String,
+    pub input: String,
+    pub rejections: Vec<PathUnionVariantRejection>,
+}
+
+impl PathUnionParseError {
+    pub fn new(
+        union: impl Into<String>,
+        input: impl Into<String>,
+        rejections: impl IntoIterator<Item = PathUnionVariantRejection>,
+    ) -> Self {
+        Self {
+            union: union.into(),
+            input: input.into(),
+            rejections: rejections.into_iter().collect(),
+        }
+    }
+
+    /// The variant the input most likely was intended to be, being the single
+    /// variant which got the furthest into the path before rejecting it, if
+    /// any.
+    pub fn most_likely_intended(&self) -> Option<&PathUnionVariantRejection> {
+        let furthest = self
+            .rejections
+            .iter()
+            .map(PathUnionVariantRejection::component_index)
+            .max()?;
+        let mut candidates = self
+            .rejections
+            .iter()
+            .filter(|r| r.component_index() == furthest);
+        let candidate = candidates.next()?;
+        if candidates.next().is_some() {
+            return None;
+        }
+        Some(candidate)
+    }
+}
+
+impl core::fmt::Display for PathUnionParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "'{}' is not a valid {}", self.input, self.union)?;
+        if let Some(most_likely) = self.most_likely_intended() {
+            write!(f, ", most likely intended as {}", most_likely.variant)?;
+        }
+        for rejection in &self.rejections {
+            write!(f, "; {}", rejection)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for PathUnionParseError {}
+
+impl From<PathUnionParseError> for CommonError {
+    fn from(value: PathUnionParseError) -> Self {
+        Self::InvalidPathUnion(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = PathUnionParseError;
+
+    fn rejection(variant: &str, component_index: usize) -> PathUnionVariantRejection {
+        let input = "m/44H/1022H/1H/525H/1460H/0";
+        let (_, spans) = HDPath::parse_with_spans(input).unwrap();
+        PathUnionVariantRejection::new(
+            variant,
+            InvalidHDPathComponent::new(component_index, "", CommonError::InvalidLength)
+                .located_in(input, &spans)
+                .into(),
+        )
+    }
+
+    #[test]
+    fn most_likely_intended_is_furthest() {
+        let sut = Sut::new(
+            "DerivationPath",
+            "",
+            [rejection("A", 2), rejection("B", 5), rejection("C", 3)],
+        );
+        assert_eq!(sut.most_likely_intended().unwrap().variant, "B");
+    }
+
+    #[test]
+    fn most_likely_intended_is_none_if_tie() {
+        let sut = Sut::new(
+            "DerivationPath",
+            "",
+            [rejection("A", 5), rejection("B", 5), rejection("C", 3)],
+        );
+        assert_eq!(sut.most_likely_intended(), None);
+    }
+
+    #[test]
+    fn most_likely_intended_is_none_if_empty() {
+        let sut = Sut::new("DerivationPath", "", []);
+        assert_eq!(sut.most_likely_intended(), None);
+    }
+
+    #[test]
+    fn non_positional_reason_has_index_zero() {
+        assert_eq!(
+            PathUnionVariantRejection::new("A", CommonError::Overflow).component_index(),
+            0
+        );
+    }
+
+    #[test]
+    fn display() {
+        let sut = Sut::new(
+            "DerivationPath",
+            "foo",
+            [
+                PathUnionVariantRejection::new("A", CommonError::InvalidPurpose),
+                PathUnionVariantRejection::new("B", CommonError::InvalidLength),
+            ],
+        );
+        assert_eq!(
+            sut.to_string(),
+            "'foo' is not a valid DerivationPath; A: Invalid Purpose; B: Invalid Length"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::prelude::*;
+
+/// An `HDPath` from the master public key `M` of only unhardened components,
+/// thus derivable from an extended public key without access to the seed,
+/// e.g. by watch-only wallets.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    MoreDebug,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[display("{}", self.to_bip32_string())]
+#[debug("{}", self.to_bip32_string_debug())]
+pub struct PublicHDPath(HDPath);
+
+impl PublicHDPath {
+    pub fn new(components: impl IntoIterator<Item = Unhardened>) -> Self {
+        Self(
+            HDPath::public(components.into_iter().map(HDPathComponent::from))
+                .expect("Unhardened components are always derivable from `M`"),
+        )
+    }
+
+    pub fn components(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = HDPathComponent> + ExactSizeIterator + Clone + '_ {
+        self.0.components()
+    }
+
+    pub fn to_hd_path(&self) -> HDPath {
+        self.0.clone()
+    }
+
+    /// This path with `component` appended.
+    pub fn child(&self, component: Unhardened) -> Self {
+        Self(
+            self.0
+                .child(component)
+                .expect("Unhardened components are always derivable from `M`"),
+        )
+    }
+}
+
+impl TryFrom<HDPath> for PublicHDPath {
+    type Error = CommonError;
+    fn try_from(path: HDPath) -> Result<Self> {
+        if !path.is_public() {
+            return Err(CommonError::ExpectedPublicRoot);
+        }
+        Ok(Self(path))
+    }
+}
+
+impl From<PublicHDPath> for HDPath {
+    fn from(path: PublicHDPath) -> Self {
+        path.0
+    }
+}
+
+impl FromBIP32Str for PublicHDPath {
+    fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
+        HDPath::from_bip32_string(s).and_then(Self::try_from)
+    }
+}
+
+impl FromStr for PublicHDPath {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bip32_string(s)
+    }
+}
+
+impl ToBIP32Str for PublicHDPath {
+    fn to_bip32_string(&self) -> String {
+        self.0.to_bip32_string()
+    }
+    fn to_bip32_string_debug(&self) -> String {
+        self.0.to_bip32_string_debug()
+    }
+}
+
+impl ToBIP32StrWithFormat for PublicHDPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        self.0.to_bip32_string_with_format(format)
+    }
+}
+
+impl HasSampleValues for PublicHDPath {
+    fn sample() -> Self {
+        Self::new([Unhardened::ZERO, Unhardened::sample()])
+    }
+    fn sample_other() -> Self {
+        Self::new([Unhardened::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = PublicHDPath;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::sample().to_string(), "M/0/237");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sut::from_str("M/0/237").unwrap(), Sut::sample());
+    }
+
+    #[test]
+    fn from_str_private_root_fails() {
+        assert_eq!(
+            Sut::from_str("m/0/237"),
+            Err(CommonError::ExpectedPublicRoot)
+        );
+    }
+
+    #[test]
+    fn from_str_hardened_fails() {
+        let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str("M/0/5H") else {
+            panic!("Expected error")
+        };
+        assert_eq!(error.component_index, 1);
+        assert_eq!(
+            error.reason,
+            CommonError::HardenedComponentInPublicDerivationPath
+        );
+    }
+
+    #[test]
+    fn child() {
+        assert_eq!(
+            Sut::new([Unhardened::ZERO]).child(Unhardened::sample()),
+            Sut::sample()
+        );
+    }
+
+    #[test]
+    fn into_hd_path() {
+        let path = HDPath::from(Sut::sample());
+        assert!(path.is_public());
+        assert_eq!(Sut::try_from(path).unwrap(), Sut::sample());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::sample();
+        assert_json_value_eq_after_roundtrip(&sut, json!("M/0/237"));
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn json_fails_for_private() {
+        assert_json_value_fails::<Sut>(json!("m/0/237"));
+    }
+}
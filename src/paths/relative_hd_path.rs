@@ -0,0 +1,166 @@
+use crate::prelude::*;
+
+/// A sequence of `HDPathComponent`s without the `m/` root, e.g. `0H/0/5`,
+/// typically the tail of an `HDPath` after some prefix, which can be joined
+/// onto another `HDPath`.
+#[derive(
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    MoreDebug,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[display("{}", self.to_bip32_string())]
+#[debug("{}", self.to_bip32_string_debug())]
+pub struct RelativeHDPath(Vec<HDPathComponent>);
+
+impl RelativeHDPath {
+    pub const fn new(components: Vec<HDPathComponent>) -> Self {
+        Self(components)
+    }
+    pub fn components(&self) -> &[HDPathComponent] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a RelativeHDPath {
+    type Item = HDPathComponent;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, HDPathComponent>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().copied()
+    }
+}
+
+impl AsRef<[HDPathComponent]> for RelativeHDPath {
+    fn as_ref(&self) -> &[HDPathComponent] {
+        self.components()
+    }
+}
+
+impl FromBIP32Str for RelativeHDPath {
+    fn from_bip32_string(s: impl AsRef<str>) -> Result<Self> {
+        let input = s.as_ref();
+        if input.starts_with('m') || input.starts_with('M') {
+            return Err(CommonError::RelativeHDPathMustNotHaveRoot);
+        }
+        let mut offset = 0;
+        let mut components = Vec::new();
+        let mut spans = Vec::new();
+        for segment in input.split(HDPath::SEPARATOR) {
+            let index = components.len();
+            let span = Span::new(offset, offset + segment.len());
+            offset = span.end + HDPath::SEPARATOR.len();
+            spans.push(span);
+            let component = if segment.is_empty() {
+                Err(CommonError::EmptyPathComponent)
+            } else {
+                HDPathComponent::from_str(segment)
+            }
+            .map_err(|e| {
+                InvalidHDPathComponent::new(
+                    index,
+                    "an index optionally suffixed with H, h, ', S or ^, or a global index",
+                    e,
+                )
+                .located_in(input, &spans)
+            })?;
+            components.push(component);
+        }
+        Ok(Self::new(components))
+    }
+}
+
+impl ToBIP32Str for RelativeHDPath {
+    fn to_bip32_string(&self) -> String {
+        HDPath::components_to_string_map(None, self.0.iter().copied(), |c| format!("{}", c))
+    }
+    fn to_bip32_string_debug(&self) -> String {
+        HDPath::components_to_string_map(None, self.0.iter().copied(), |c| format!("{:?}", c))
+    }
+}
+
+impl ToBIP32StrWithFormat for RelativeHDPath {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        format.format_components(None, self)
+    }
+}
+
+impl FromStr for RelativeHDPath {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bip32_string(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Sut = RelativeHDPath;
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::from_str("0H/0/5S").unwrap().to_string(), "0H/0/5S");
+        assert_eq!(Sut::default().to_string(), "");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Sut::from_str("0H/0/5S").unwrap()),
+            "0'/0/5^"
+        );
+    }
+
+    #[test]
+    fn from_str_non_canonical() {
+        assert_eq!(
+            Sut::from_str("0'/0/5^").unwrap(),
+            Sut::from_str("0H/0/5S").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_with_root_fails() {
+        assert_eq!(
+            Sut::from_str("m/0H"),
+            Err(CommonError::RelativeHDPathMustNotHaveRoot)
+        );
+        assert_eq!(
+            Sut::from_str("M/0"),
+            Err(CommonError::RelativeHDPathMustNotHaveRoot)
+        );
+    }
+
+    #[test]
+    fn from_str_empty_segment_fails() {
+        for (s, component_index) in [("", 0), ("/0", 0), ("0//5", 1), ("0/", 1)] {
+            let Err(CommonError::InvalidBIP32Path(error)) = Sut::from_str(s) else {
+                panic!("Expected error for {:?}", s)
+            };
+            assert_eq!(error.component_index, component_index);
+            assert_eq!(error.reason, CommonError::EmptyPathComponent);
+        }
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!(Sut::from_str("0H/X").is_err());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = Sut::from_str("1460H/0H").unwrap();
+        assert_json_value_eq_after_roundtrip(&sut, json!("1460H/0H"));
+        assert_json_roundtrip(&sut);
+    }
+}
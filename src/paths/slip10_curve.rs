@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+/// The elliptic curve a key is derived on using SLIP10, dictated by the kind
+/// of derivation path: Babylon CAP26 paths use `Curve25519` and Olympia
+/// `BIP44LikePath`s use `Secp256k1`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Display, MoreDebug)]
+pub enum SLIP10Curve {
+    /// Used for Ed25519 keys by CAP26 paths.
+    #[display("curve25519")]
+    Curve25519,
+
+    /// Used for ECDSA keys by Olympia BIP44 like paths.
+    #[display("secp256k1")]
+    Secp256k1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = SLIP10Curve;
+
+    #[test]
+    fn display() {
+        assert_eq!(Sut::Curve25519.to_string(), "curve25519");
+        assert_eq!(Sut::Secp256k1.to_string(), "secp256k1");
+    }
+
+    #[test]
+    fn curve_of_paths() {
+        assert_eq!(CAP26AccountPath::sample().curve(), Sut::Curve25519);
+        assert_eq!(CAP26IdentityPath::sample().curve(), Sut::Curve25519);
+        assert_eq!(CAP26GetIDPath.curve(), Sut::Curve25519);
+        assert_eq!(BIP44LikePath::sample().curve(), Sut::Secp256k1);
+    }
+
+    #[test]
+    fn curve_of_unions() {
+        assert_eq!(AccountPath::sample().curve(), Sut::Curve25519);
+        assert_eq!(AccountPath::sample_other().curve(), Sut::Secp256k1);
+        assert_eq!(
+            DerivationPath::identity(CAP26IdentityPath::sample()).curve(),
+            Sut::Curve25519
+        );
+        assert_eq!(
+            DerivationPath::bip44like(BIP44LikePath::sample()).curve(),
+            Sut::Secp256k1
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use crate::prelude::*;
+
+pub trait HasSLIP10Curve {
+    /// The curve keys at this path are derived on.
+    fn curve(&self) -> SLIP10Curve;
+}
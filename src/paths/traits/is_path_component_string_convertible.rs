@@ -3,4 +3,7 @@ pub trait IsPathComponentStringConvertible {
     const NON_CANONICAL_SUFFIXES: &'static str;
     const ACCEPTABLE_SUFFIXES: [&'static str; 2] =
         [Self::CANONICAL_SUFFIX, Self::NON_CANONICAL_SUFFIXES];
+    /// Further non-canonical suffixes accepted when parsing but never
+    /// produced, e.g. `h` as used by BIP380 output descriptors.
+    const LENIENT_SUFFIXES: &'static [&'static str] = &[];
 }
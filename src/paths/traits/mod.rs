@@ -1,11 +1,17 @@
 mod from_bip32_str;
+mod has_slip10_curve;
 mod is_network_aware;
 mod is_path_component_string_convertible;
 mod is_security_state_aware;
 mod to_bip32_str;
+mod to_bip32_str_with_format;
+mod try_from_hd_path_components;
 
 pub use from_bip32_str::*;
+pub use has_slip10_curve::*;
 pub use is_network_aware::*;
 pub use is_path_component_string_convertible::*;
 pub use is_security_state_aware::*;
 pub use to_bip32_str::*;
+pub use to_bip32_str_with_format::*;
+pub use try_from_hd_path_components::*;
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+pub trait ToBIP32StrWithFormat {
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String;
+}
+
+impl<T> ToBIP32StrWithFormat for T
+where
+    T: IsPathComponentStringConvertible + IsInLocalKeySpace,
+{
+    fn to_bip32_string_with_format(&self, format: HDPathFormat) -> String {
+        let component = HDPathComponent::from_local_key_space(
+            u32::from(self.index_in_local_key_space()),
+            self.key_space(),
+        )
+        .expect("Index in local key space is always valid in its key space");
+        format.format_component(&component)
+    }
+}
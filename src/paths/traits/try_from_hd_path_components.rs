@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+/// A path type which can be validated from the components of an `HDPath`,
+/// reporting which component is invalid on failure.
+pub trait TryFromHDPathComponents: Sized {
+    fn try_from_hd_path_components(path: &HDPath) -> Result<Self, InvalidHDPathComponent>;
+
+    /// Parses `s` as an `HDPath` and validates its components, locating any
+    /// error in `s`.
+    fn from_bip32_string_validating_components(s: impl AsRef<str>) -> Result<Self> {
+        Self::from_bip32_string_with_mode(s, HDPathParsingMode::Lenient)
+    }
+
+    /// Parses `s` as an `HDPath` according to `mode` and validates its
+    /// components, locating any error in `s`.
+    fn from_bip32_string_with_mode(s: impl AsRef<str>, mode: HDPathParsingMode) -> Result<Self> {
+        let s = s.as_ref();
+        let (path, spans) = HDPath::parse_with_spans_in_mode(s, mode)?;
+        Self::try_from_hd_path_components(&path).map_err(|e| e.located_in(s, &spans).into())
+    }
+}
@@ -1,6 +1,6 @@
-use super::CAP26EntityKind;
+use crate::prelude::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, thiserror::Error)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, thiserror::Error)]
 pub enum CommonError {
     #[error("IndexNotHardened {bad_index:?}")]
     IndexNotHardened { bad_index: u32 },
@@ -61,5 +61,142 @@ pub enum CommonError {
         expected: CAP26EntityKind,
         found: CAP26EntityKind,
     },
+
+    #[error("A range in an HDPathPattern must start and end in the same key space")]
+    HDPathPatternRangeSpansMultipleKeySpaces,
+
+    #[error("A relative HDPath must not start with the root `m/`")]
+    RelativeHDPathMustNotHaveRoot,
+
+    #[error("BIP32 path must start with the root `m`")]
+    MissingRoot,
+
+    #[error("Hardened or securified components cannot be derived from the public root `M`")]
+    HardenedComponentInPublicDerivationPath,
+
+    #[error("Expected a path from the public root `M`")]
+    ExpectedPublicRoot,
+
+    #[error("Empty path component")]
+    EmptyPathComponent,
+
+    #[error("Path component contains whitespace")]
+    PathComponentContainsWhitespace,
+
+    #[error("Path component has leading zeros")]
+    PathComponentHasLeadingZeros,
+
+    #[error("Path mixes canonical and non-canonical suffixes")]
+    MixedCanonicalAndNonCanonicalSuffixes,
+
+    #[error("Path is deeper than the max depth of {}", HDPath::MAX_DEPTH)]
+    DepthExceedsMax,
+
+    #[error("Ed25519 derivation requires hardened components, but component {index} `{component}` is unhardened")]
+    UnhardenedComponentInEd25519Derivation {
+        index: usize,
+        component: HDPathComponent,
+    },
+
+    #[error("Private key derivation requires a path from the private root `m`")]
+    PrivateKeyDerivationFromPublicRoot,
+
+    #[error("Expected a key on {expected} but found one on {found}")]
+    CurveMismatch {
+        expected: SLIP10Curve,
+        found: SLIP10Curve,
+    },
+
+    #[error("Invalid Ed25519 signature")]
+    InvalidEd25519Signature,
+
+    #[error("Invalid Secp256k1 signature")]
+    InvalidSecp256k1Signature,
+
+    #[error("Failed to recover Secp256k1 public key from signature")]
+    Secp256k1PublicKeyRecoveryFailed,
+
+    #[error("Public key derivation requires unhardened components, but `{component}` is hardened")]
+    HardenedComponentInPublicKeyDerivation { component: HDPathComponent },
+
+    #[error("WrongKeyKind found: {found:?}, expected: {expected:?}")]
+    WrongKeyKind {
+        expected: CAP26KeyKind,
+        found: CAP26KeyKind,
+    },
+
+    #[error("Paths of key kind MessageEncryption are for X25519 key agreement, not signing keys")]
+    MessageEncryptionKeyKindRequiresX25519,
+
+    #[error("The OS random number generator is unavailable")]
+    SecureRandomnessUnavailable,
+
+    #[error("X25519 shared secret is non-contributory, the public key is of low order")]
+    NonContributoryX25519SharedSecret,
+
+    #[error("Invalid encrypted message")]
+    InvalidEncryptedMessage,
+
+    #[error("Failed to decrypt message")]
+    MessageDecryptionFailed,
+
+    #[error("Invalid Bech32 encoding")]
+    InvalidBech32,
+
+    #[error("Invalid Bech32m encoding")]
+    InvalidBech32m,
+
+    #[error("Address HRP is not of the expected entity kind")]
+    InvalidAddressHRP,
+
+    #[error("Address does not have the length of a node id")]
+    InvalidAddressLength,
+
+    #[error("Address is not of a virtual entity type of the expected entity kind")]
+    InvalidAddressEntityType,
+
+    #[error("Olympia address does not start with the version byte 0x04")]
+    InvalidOlympiaAddressVersionByte,
+
+    #[error("Invalid Base58Check encoding")]
+    InvalidBase58Check,
+
+    #[error("Invalid extended key length, expected {expected} bytes but found {found}")]
+    InvalidExtendedKeyLength { expected: usize, found: usize },
+
+    #[error("Invalid extended key version")]
+    InvalidExtendedKeyVersion,
+
+    #[error("Extended key of depth 0 must have zero parent fingerprint and child number")]
+    InvalidExtendedKeyMasterFields,
+
+    #[error("Invalid Ed25519 public key")]
+    InvalidEd25519PublicKey,
+
+    #[error("Invalid Secp256k1 private key")]
+    InvalidSecp256k1PrivateKey,
+
+    #[error("Invalid Secp256k1 public key")]
+    InvalidSecp256k1PublicKey,
+
+    #[error("Invalid FactorSourceKind")]
+    InvalidFactorSourceKind,
+
+    #[error("Invalid FactorSourceIDFromHash, expected `kind:hex` with a 32 byte body")]
+    InvalidFactorSourceIDFromHash,
+
+    #[error("{0}")]
+    InvalidBIP32Path(Box<BIP32PathParseError>),
+
+    #[error("{0}")]
+    InvalidPathUnion(Box<PathUnionParseError>),
+}
+
+/// Lets infallible conversions, e.g. of a `DerivationPath` into itself, be
+/// used where the conversion error must be a `CommonError`.
+impl From<core::convert::Infallible> for CommonError {
+    fn from(infallible: core::convert::Infallible) -> Self {
+        match infallible {}
+    }
 }
-pub type Result<T, E = CommonError> = std::result::Result<T, E>;
+pub type Result<T, E = CommonError> = core::result::Result<T, E>;
@@ -4,28 +4,103 @@ use crate::prelude::*;
 
 use bip39::Mnemonic;
 use crypto::{
-    keys::slip10::{self as IotaSlip10, Hardened as IotaSlip10PathComponent},
+    keys::slip10::{
+        self as IotaSlip10, Hardened as IotaSlip10PathComponent,
+        NonHardened as IotaSlip10UnhardenedPathComponent,
+    },
+    keys::x25519 as IotaX25519,
     signatures::ed25519 as IotaSlip10Ed25519,
     signatures::secp256k1_ecdsa as IotaSlip10Secp256k1,
 };
-use zeroize::Zeroizing;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
+/// The 64 bytes derived from a mnemonic and passphrase, the root secret of
+/// all keys. Zeroized on drop, redacted in `Debug` and compared in constant
+/// time.
+#[derive(Zeroize, ZeroizeOnDrop, MoreDebug)]
+#[debug("BIP39Seed(<redacted>)")]
 pub struct BIP39Seed([u8; 64]);
+impl BIP39Seed {
+    /// The caller is responsible for zeroizing its copy of `bytes`.
+    pub fn from_bytes(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+}
+impl PartialEq for BIP39Seed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+impl Eq for BIP39Seed {}
+
+impl HasSampleValues for Mnemonic {
+    fn sample() -> Self {
+        Self::from_str("equip will roof matter pink blind book anxiety banner elbow sun young")
+            .expect("Valid mnemonic")
+    }
+    fn sample_other() -> Self {
+        Self::from_str("device phone sign source sample device sample device sample device sample device sample device sample device sample device phone sign source sample device swim")
+            .expect("Valid mnemonic")
+    }
+}
 
+impl HasSampleValues for BIP39Seed {
+    /// The seed of `Mnemonic::sample` with an empty passphrase.
+    fn sample() -> Self {
+        Mnemonic::sample().to_bip39_seed("")
+    }
+    /// The seed of `Mnemonic::sample_other` with an empty passphrase.
+    fn sample_other() -> Self {
+        Mnemonic::sample_other().to_bip39_seed("")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, MoreDebug)]
+#[debug("{}", self.to_hex())]
 pub struct Ed25519PublicKey(IotaSlip10Ed25519::PublicKey);
 impl Ed25519PublicKey {
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        IotaSlip10Ed25519::PublicKey::try_from_bytes(*bytes)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidEd25519PublicKey)
+    }
     pub fn to_bytes(&self) -> [u8; 32] {
         self.0.to_bytes()
     }
+    /// Whether `signature` is a valid signature of `message` by this key.
+    pub fn verify(&self, signature: &Ed25519Signature, message: impl AsRef<[u8]>) -> bool {
+        self.0.verify(
+            &IotaSlip10Ed25519::Signature::from_bytes(signature.to_bytes()),
+            message.as_ref(),
+        )
+    }
     pub fn to_hex(&self) -> String {
         hex::encode(self.to_bytes())
     }
 }
+/// Zeroized on drop, redacted in `Debug` and compared in constant time.
+#[derive(Clone, ZeroizeOnDrop, MoreDebug)]
+#[debug("Ed25519PrivateKey(<redacted>)")]
 pub struct Ed25519PrivateKey(IotaSlip10Ed25519::SecretKey);
+impl PartialEq for Ed25519PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ct_eq(&*other.to_bytes()).into()
+    }
+}
+impl Eq for Ed25519PrivateKey {}
 impl Ed25519PrivateKey {
+    /// The caller is responsible for zeroizing its copy of `bytes`.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(IotaSlip10Ed25519::SecretKey::from_bytes(bytes))
+    }
     pub fn public_key(&self) -> Ed25519PublicKey {
         Ed25519PublicKey(self.0.public_key())
     }
+    /// RFC8032 deterministic signature of `message`.
+    pub fn sign(&self, message: impl AsRef<[u8]>) -> Ed25519Signature {
+        Ed25519Signature(self.0.sign(message.as_ref()).to_bytes())
+    }
     pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
         self.0.to_bytes()
     }
@@ -34,21 +109,103 @@ impl Ed25519PrivateKey {
     }
 }
 
+/// A 64 bytes Ed25519 signature, displayed and serialized as hex.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, Display, MoreDebug, SerializeDisplay, DeserializeFromStr,
+)]
+#[display("{}", self.to_hex())]
+#[debug("{}", self.to_hex())]
+pub struct Ed25519Signature([u8; 64]);
+impl Ed25519Signature {
+    pub fn from_bytes(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0
+    }
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+}
+impl FromStr for Ed25519Signature {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        hex::decode(s)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or(CommonError::InvalidEd25519Signature)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, MoreDebug)]
+#[debug("{}", self.to_hex())]
 pub struct Secp256k1PublicKey(IotaSlip10Secp256k1::PublicKey);
 impl Secp256k1PublicKey {
+    /// A compressed SEC1 public key.
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self> {
+        IotaSlip10Secp256k1::PublicKey::try_from_bytes(bytes)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidSecp256k1PublicKey)
+    }
     pub fn to_bytes(&self) -> [u8; 33] {
         self.0.to_bytes()
     }
+    /// Whether `signature` is a valid ECDSA signature of the SHA256 hash of
+    /// `message` by this key.
+    pub fn verify(&self, signature: &Secp256k1Signature, message: impl AsRef<[u8]>) -> bool {
+        self.0.verify_sha256(signature.0.as_ref(), message.as_ref())
+    }
+
+    /// BIP32 CKDpub, the public key and chain code of the child at `index`
+    /// of this key with `chain_code`.
+    pub(crate) fn child_key(&self, chain_code: &[u8; 32], index: Unhardened) -> (Self, [u8; 32]) {
+        let mut extended_bytes = [0u8; 65];
+        extended_bytes[..33].copy_from_slice(&self.to_bytes());
+        extended_bytes[33..].copy_from_slice(chain_code);
+        let parent = IotaSlip10::Slip10::<IotaSlip10Secp256k1::PublicKey>::try_from_extended_bytes(
+            &extended_bytes,
+        )
+        .expect("Valid public key");
+        let segment = IotaSlip10UnhardenedPathComponent::try_from(
+            HDPathComponent::from(index).map_to_global_key_space(),
+        )
+        .expect("Unhardened index");
+        let child = parent.child_key(segment);
+        (Self(child.public_key()), *child.chain_code())
+    }
     pub fn to_hex(&self) -> String {
         hex::encode(self.to_bytes())
     }
 }
 
+/// Zeroized on drop, redacted in `Debug` and compared in constant time.
+#[derive(Clone, ZeroizeOnDrop, MoreDebug)]
+#[debug("Secp256k1PrivateKey(<redacted>)")]
 pub struct Secp256k1PrivateKey(IotaSlip10Secp256k1::SecretKey);
+impl PartialEq for Secp256k1PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ct_eq(&*other.to_bytes()).into()
+    }
+}
+impl Eq for Secp256k1PrivateKey {}
 impl Secp256k1PrivateKey {
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        IotaSlip10Secp256k1::SecretKey::try_from_bytes(bytes)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidSecp256k1PrivateKey)
+    }
     pub fn public_key(&self) -> Secp256k1PublicKey {
         Secp256k1PublicKey(self.0.public_key())
     }
+    /// RFC6979 deterministic ECDSA signature of the SHA256 hash of
+    /// `message`, recoverable and with a low `s`.
+    pub fn sign(&self, message: impl AsRef<[u8]>) -> Secp256k1Signature {
+        self.0
+            .try_sign_sha256(message.as_ref())
+            .map(Secp256k1Signature)
+            .expect("RFC6979 signing only fails with negligible probability")
+    }
     pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
         self.0.to_bytes()
     }
@@ -58,12 +215,140 @@ impl Secp256k1PrivateKey {
 }
 
 impl HDPath {
-    fn hardened_chain(&self) -> Vec<IotaSlip10PathComponent> {
-        self.components()
-            .iter()
-            .map(|c| c.map_to_global_key_space())
-            .map(|v| IotaSlip10PathComponent::try_from(v).expect("Should work"))
-            .collect::<Vec<IotaSlip10PathComponent>>()
+    /// The components of this path as SLIP10 segments for Ed25519, which
+    /// only supports hardened derivation, fails with the first unhardened
+    /// component.
+    fn hardened_chain(&self) -> Result<impl Iterator<Item = IotaSlip10PathComponent> + '_> {
+        if let Some((index, component)) = self
+            .components()
+            .enumerate()
+            .find(|(_, component)| component.is_unhardened())
+        {
+            return Err(CommonError::UnhardenedComponentInEd25519Derivation { index, component });
+        }
+        Ok(self.global_indices().iter().map(|v| {
+            IotaSlip10PathComponent::try_from(*v).expect("Checked all components are hardened")
+        }))
+    }
+
+    /// Private keys can only be derived from paths from the root `m`.
+    fn check_private_root(&self) -> Result<()> {
+        if self.is_public() {
+            return Err(CommonError::PrivateKeyDerivationFromPublicRoot);
+        }
+        Ok(())
+    }
+}
+
+/// A 65 bytes recoverable Secp256k1 ECDSA signature, `r || s || v` where `v`
+/// is the recovery id, displayed and serialized as hex.
+#[derive(Clone, PartialEq, Eq, Hash, Display, MoreDebug, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", self.to_hex())]
+#[debug("{}", self.to_hex())]
+pub struct Secp256k1Signature(IotaSlip10Secp256k1::RecoverableSignature);
+impl Secp256k1Signature {
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Self> {
+        IotaSlip10Secp256k1::RecoverableSignature::try_from_bytes(bytes)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidSecp256k1Signature)
+    }
+    pub fn to_bytes(&self) -> [u8; 65] {
+        self.0.to_bytes()
+    }
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+    /// The public key which signed the SHA256 hash of `message`, verify the
+    /// returned key against an expected one, any signature recovers to some
+    /// key.
+    pub fn recover_public_key(&self, message: impl AsRef<[u8]>) -> Result<Secp256k1PublicKey> {
+        self.0
+            .recover_sha256(message.as_ref())
+            .map(Secp256k1PublicKey)
+            .ok_or(CommonError::Secp256k1PublicKeyRecoveryFailed)
+    }
+}
+impl FromStr for Secp256k1Signature {
+    type Err = CommonError;
+    fn from_str(s: &str) -> Result<Self> {
+        hex::decode(s)
+            .ok()
+            .and_then(|bytes| <[u8; 65]>::try_from(bytes).ok())
+            .ok_or(CommonError::InvalidSecp256k1Signature)
+            .and_then(|bytes| Self::from_bytes(&bytes))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, MoreDebug)]
+#[debug("{}", self.to_hex())]
+pub struct X25519PublicKey(IotaX25519::PublicKey);
+impl X25519PublicKey {
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(IotaX25519::PublicKey::from_bytes(bytes))
+    }
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+}
+
+/// A key for Diffie-Hellman key agreement, derived from the Ed25519 key at a
+/// CAP26 path of key kind `MessageEncryption`, see
+/// `BIP39Seed::derive_x25519_private_key`. Zeroized on drop, redacted in
+/// `Debug` and compared in constant time.
+#[derive(ZeroizeOnDrop, MoreDebug)]
+#[debug("X25519PrivateKey(<redacted>)")]
+pub struct X25519PrivateKey(IotaX25519::SecretKey);
+impl Clone for X25519PrivateKey {
+    fn clone(&self) -> Self {
+        Self::from_bytes(&self.to_bytes())
+    }
+}
+impl PartialEq for X25519PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().ct_eq(&*other.to_bytes()).into()
+    }
+}
+impl Eq for X25519PrivateKey {}
+impl X25519PrivateKey {
+    /// The caller is responsible for zeroizing its copy of `bytes`.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(IotaX25519::SecretKey::from_bytes(*bytes))
+    }
+    pub fn public_key(&self) -> X25519PublicKey {
+        X25519PublicKey(self.0.public_key())
+    }
+    pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.0.to_bytes())
+    }
+    /// The secret shared with the owner of `public_key`, who computes the
+    /// same secret from their private key and our public key. Fails if
+    /// `public_key` is of low order, making the secret predictable.
+    pub fn diffie_hellman(&self, public_key: &X25519PublicKey) -> Result<X25519SharedSecret> {
+        let shared_secret = self.0.diffie_hellman(&public_key.0);
+        if !shared_secret.was_contributory() {
+            return Err(CommonError::NonContributoryX25519SharedSecret);
+        }
+        Ok(X25519SharedSecret(shared_secret))
+    }
+}
+
+/// The result of X25519 key agreement, used to key `EncryptedMessage`s.
+/// Zeroized on drop, redacted in `Debug` and compared in constant time.
+#[derive(ZeroizeOnDrop, MoreDebug)]
+#[debug("X25519SharedSecret(<redacted>)")]
+pub struct X25519SharedSecret(IotaX25519::SharedSecret);
+impl PartialEq for X25519SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes().ct_eq(other.as_bytes()).into()
+    }
+}
+impl Eq for X25519SharedSecret {}
+impl X25519SharedSecret {
+    pub(crate) fn as_bytes(&self) -> &[u8; 32] {
+        self.0.as_bytes()
     }
 }
 
@@ -78,29 +363,172 @@ impl BIP39Seed {
         iota_seed.derive(chain)
     }
 
-    fn _derive_ed25519_private_key(&self, path: &HDPath) -> IotaSlip10Ed25519::SecretKey {
-        self._derive_slip10_private_key::<_, _>(path.hardened_chain().into_iter())
-            .secret_key()
+    fn _derive_ed25519_private_key(&self, path: &HDPath) -> Result<IotaSlip10Ed25519::SecretKey> {
+        path.check_private_root()?;
+        let chain = path.hardened_chain()?;
+        Ok(self._derive_slip10_private_key::<_, _>(chain).secret_key())
     }
 
-    fn _derive_secp256k1_private_key(&self, path: &HDPath) -> IotaSlip10Secp256k1::SecretKey {
-        self._derive_slip10_private_key::<_, _>(
-            path.components()
-                .iter()
-                .cloned()
-                .map(|c| c.map_to_global_key_space()),
-        )
-        .secret_key()
+    fn _derive_secp256k1_private_key(
+        &self,
+        path: &HDPath,
+    ) -> Result<IotaSlip10Secp256k1::SecretKey> {
+        path.check_private_root()?;
+        let chain = path.global_indices().iter().copied();
+        Ok(self._derive_slip10_private_key::<_, _>(chain).secret_key())
+    }
+
+    /// Derives the private key at `path` on the curve dictated by the kind
+    /// of path, Curve25519 for CAP26 paths and Secp256k1 for BIP44 like
+    /// paths.
+    pub fn derive_private_key(&self, path: &DerivationPath) -> Result<PrivateKey> {
+        self.derive_private_key_on_curve(path, path.curve())
+    }
+
+    /// Derives the private key at `path` on `curve`, fails if `curve` is
+    /// not the curve dictated by the kind of path.
+    pub fn derive_private_key_on_curve(
+        &self,
+        path: &DerivationPath,
+        curve: SLIP10Curve,
+    ) -> Result<PrivateKey> {
+        if path.key_kind() == Some(CAP26KeyKind::MessageEncryption) {
+            return Err(CommonError::MessageEncryptionKeyKindRequiresX25519);
+        }
+        if curve != path.curve() {
+            return Err(CommonError::CurveMismatch {
+                expected: path.curve(),
+                found: curve,
+            });
+        }
+        match curve {
+            SLIP10Curve::Curve25519 => self
+                .derive_ed25519_private_key(path.to_hd_path())
+                .map(PrivateKey::from),
+            SLIP10Curve::Secp256k1 => self
+                .derive_secp256k1_private_key(path.to_hd_path())
+                .map(PrivateKey::from),
+        }
     }
 
-    pub fn derive_secp256k1_private_key(&self, hd_path: impl Into<HDPath>) -> Secp256k1PrivateKey {
-        let inner = self._derive_secp256k1_private_key(&hd_path.into());
-        Secp256k1PrivateKey(inner)
+    /// Derives the BIP32 extended Secp256k1 private key at `hd_path`, which
+    /// also records the chain code, depth, parent fingerprint and child
+    /// number. Fails if the path is from the public root `M`.
+    pub fn derive_extended_private_key(
+        &self,
+        hd_path: impl Into<HDPath>,
+    ) -> Result<ExtendedPrivateKey> {
+        let path = hd_path.into();
+        path.check_private_root()?;
+        let master =
+            IotaSlip10::Seed::from_bytes(&self.0).to_master_key::<IotaSlip10Secp256k1::SecretKey>();
+        let Some(parent_path) = path.parent() else {
+            return Ok(ExtendedPrivateKey::new(
+                0,
+                [0; 4],
+                HDPathComponent::from_global(0),
+                *master.chain_code(),
+                Secp256k1PrivateKey(master.secret_key()),
+            ));
+        };
+        let parent = master.derive(parent_path.global_indices().iter().copied());
+        let child_number = path.components().last().expect("Path has a parent");
+        let child = parent.child_key(child_number.map_to_global_key_space());
+        let parent_fingerprint = fingerprint(&Secp256k1PublicKey(parent.secret_key().public_key()));
+        Ok(ExtendedPrivateKey::new(
+            path.depth() as u8,
+            parent_fingerprint,
+            child_number,
+            *child.chain_code(),
+            Secp256k1PrivateKey(child.secret_key()),
+        ))
     }
 
-    pub fn derive_ed25519_private_key(&self, hd_path: impl Into<HDPath>) -> Ed25519PrivateKey {
-        let inner = self._derive_ed25519_private_key(&hd_path.into());
-        Ed25519PrivateKey(inner)
+    /// Derives the Secp256k1 private key at `hd_path`, fails if the path is
+    /// from the public root `M`.
+    pub fn derive_secp256k1_private_key(
+        &self,
+        hd_path: impl Into<HDPath>,
+    ) -> Result<Secp256k1PrivateKey> {
+        self._derive_secp256k1_private_key(&hd_path.into())
+            .map(Secp256k1PrivateKey)
+    }
+
+    /// Derives the Ed25519 private key at `hd_path`, fails if the path is
+    /// from the public root `M`, has any unhardened component, since
+    /// SLIP10 only supports hardened derivation for Ed25519, or is a CAP26
+    /// path of key kind `MessageEncryption`, see `derive_x25519_private_key`.
+    pub fn derive_ed25519_private_key(
+        &self,
+        hd_path: impl Into<HDPath>,
+    ) -> Result<Ed25519PrivateKey> {
+        let hd_path = hd_path.into();
+        if UnvalidatedCAP26Path::try_from_hd_path_components(&hd_path)
+            .is_ok_and(|path| path.key_kind == CAP26KeyKind::MessageEncryption)
+        {
+            return Err(CommonError::MessageEncryptionKeyKindRequiresX25519);
+        }
+        self._derive_ed25519_private_key(&hd_path)
+            .map(Ed25519PrivateKey)
+    }
+
+    /// The virtual address of the account controlled by the Ed25519 key at
+    /// `path`, on the network of `path`, fails unless `path` is of key kind
+    /// `TransactionSigning`.
+    pub fn derive_account_address(&self, path: &CAP26AccountPath) -> Result<AccountAddress> {
+        self.derive_transaction_signing_public_key(path)
+            .map(|key| AccountAddress::new(key, path.network_id()))
+    }
+
+    /// The virtual address of the identity controlled by the Ed25519 key at
+    /// `path`, on the network of `path`, fails unless `path` is of key kind
+    /// `TransactionSigning`.
+    pub fn derive_identity_address(&self, path: &CAP26IdentityPath) -> Result<IdentityAddress> {
+        self.derive_transaction_signing_public_key(path)
+            .map(|key| IdentityAddress::new(key, path.network_id()))
+    }
+
+    /// The address of the Olympia account controlled by the Secp256k1 key at
+    /// `path`.
+    pub fn derive_legacy_olympia_account_address(
+        &self,
+        path: &BIP44LikePath,
+    ) -> Result<LegacyOlympiaAccountAddress> {
+        self.derive_secp256k1_private_key(path.clone())
+            .map(|key| LegacyOlympiaAccountAddress::new(key.public_key()))
+    }
+
+    fn derive_transaction_signing_public_key<P>(&self, path: &P) -> Result<Ed25519PublicKey>
+    where
+        P: HasKeyKind + Clone + Into<HDPath>,
+    {
+        let key_kind = path.key_kind();
+        if key_kind != CAP26KeyKind::TransactionSigning {
+            return Err(CommonError::WrongKeyKind {
+                expected: CAP26KeyKind::TransactionSigning,
+                found: key_kind,
+            });
+        }
+        self.derive_ed25519_private_key(path.clone())
+            .map(|key| key.public_key())
+    }
+
+    /// Derives the X25519 key for key agreement at `path`, converted from
+    /// the Ed25519 key at `path`, fails unless `path` is of key kind
+    /// `MessageEncryption`.
+    pub fn derive_x25519_private_key<P>(&self, path: P) -> Result<X25519PrivateKey>
+    where
+        P: HasKeyKind + Into<HDPath>,
+    {
+        let key_kind = path.key_kind();
+        if key_kind != CAP26KeyKind::MessageEncryption {
+            return Err(CommonError::WrongKeyKind {
+                expected: CAP26KeyKind::MessageEncryption,
+                found: key_kind,
+            });
+        }
+        let ed25519 = self._derive_ed25519_private_key(&path.into())?;
+        Ok(X25519PrivateKey(IotaX25519::SecretKey::from(&ed25519)))
     }
 }
 
@@ -109,22 +537,10 @@ pub trait ToSeed {
 }
 impl ToSeed for Mnemonic {
     fn to_bip39_seed<'a, P: Into<Cow<'a, str>>>(&self, passphrase: P) -> BIP39Seed {
-        BIP39Seed(self.to_seed(passphrase))
-    }
-}
-
-pub struct FactorSourceIDFromHash;
-impl FactorSourceIDFromHash {
-    pub fn from_mnemonic_with_passphrase(
-        mnemonic: impl AsRef<str>,
-        passphrase: impl AsRef<str>,
-    ) -> String {
-        let mnemonic = Mnemonic::from_str(mnemonic.as_ref()).unwrap();
-        let seed = mnemonic.to_bip39_seed(passphrase.as_ref());
-        let private_key = seed.derive_ed25519_private_key(CAP26GetIDPath);
-        let public_key_bytes = private_key.public_key().to_bytes();
-        let hash = blake2b_256_hash(public_key_bytes);
-        hex::encode(hash)
+        let mut bytes = self.to_seed(passphrase);
+        let seed = BIP39Seed(bytes);
+        bytes.zeroize();
+        seed
     }
 }
 
@@ -148,7 +564,7 @@ mod tests {
         let seed = mnemonic.to_bip39_seed(passphrase.as_ref());
         let path = P::from_str(path.as_ref()).unwrap();
         let path: HDPath = path.into();
-        let private_key = seed.derive_ed25519_private_key(path);
+        let private_key = seed.derive_ed25519_private_key(path).unwrap();
         assert(&private_key, &private_key.public_key());
     }
 
@@ -199,12 +615,277 @@ mod tests {
     }
 
     #[test]
-    fn derivation_get_id() {
-        let mnemonic_sample_device = "device phone sign source sample device sample device sample device sample device sample device sample device sample device phone sign source sample device swim";
-        let id_from_hash =
-            FactorSourceIDFromHash::from_mnemonic_with_passphrase(mnemonic_sample_device, "");
-        let expected = "f1a93d324dd0f2bff89963ab81ed6e0c2ee7e18c0827dc1d3576b2d9f26bbd0a";
-        assert_eq!(expected, id_from_hash)
+    fn unhardened_component_is_err() {
+        let path = BIP44LikePath::from_str("m/44H/1022H/0H/0/5H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample().derive_ed25519_private_key(path).err(),
+            Some(CommonError::UnhardenedComponentInEd25519Derivation {
+                index: 3,
+                component: HDPathComponent::from_str("0").unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn unhardened_component_err_names_component() {
+        let path = HDPath::from_str("m/44H/1022H/7").unwrap();
+        let error = BIP39Seed::sample()
+            .derive_ed25519_private_key(path)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Ed25519 derivation requires hardened components, but component 2 `7` is unhardened"
+        );
+    }
+
+    #[test]
+    fn public_root_is_err() {
+        let path = HDPath::from_str("M/0/1").unwrap();
+        assert_eq!(
+            BIP39Seed::sample().derive_ed25519_private_key(path).err(),
+            Some(CommonError::PrivateKeyDerivationFromPublicRoot)
+        );
+    }
+
+    /// RFC8032 test vector 1
+    #[test]
+    fn sign_rfc8032_vector_1() {
+        let bytes = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .unwrap();
+        let sut = Ed25519PrivateKey::from_bytes(&bytes.try_into().unwrap());
+        assert_eq!(
+            sut.public_key().to_hex(),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        let signature = sut.sign([]);
+        assert_eq!(signature.to_string(), "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b");
+        assert!(sut.public_key().verify(&signature, []));
+    }
+
+    #[test]
+    fn verify_ed25519() {
+        let sut = BIP39Seed::sample()
+            .derive_ed25519_private_key(CAP26GetIDPath)
+            .unwrap();
+        let signature = sut.sign("Hello");
+        assert!(sut.public_key().verify(&signature, "Hello"));
+        assert!(!sut.public_key().verify(&signature, "Hello!"));
+        let other = BIP39Seed::sample()
+            .derive_ed25519_private_key(HDPath::from_str("m/0H").unwrap())
+            .unwrap();
+        assert!(!other.public_key().verify(&signature, "Hello"));
+    }
+
+    #[test]
+    fn ed25519_signature_json_roundtrip() {
+        let sut = BIP39Seed::sample()
+            .derive_ed25519_private_key(CAP26GetIDPath)
+            .unwrap()
+            .sign("Hello");
+        assert_json_value_eq_after_roundtrip(&sut, serde_json::json!(sut.to_hex()));
+        assert_eq!(Ed25519Signature::from_str(&sut.to_hex()).unwrap(), sut);
+    }
+
+    #[test]
+    fn ed25519_signature_from_str_invalid() {
+        assert_eq!(
+            Ed25519Signature::from_str("deadbeef"),
+            Err(CommonError::InvalidEd25519Signature)
+        );
+        assert_eq!(
+            Ed25519Signature::from_str("not hex"),
+            Err(CommonError::InvalidEd25519Signature)
+        );
+    }
+
+    fn message_encryption_path(key_kind: CAP26KeyKind) -> CAP26AccountPath {
+        CAP26AccountPath::new(
+            NetworkID::Mainnet,
+            key_kind,
+            Hardened::from_local_key_space(0u32, IsSecurified(false)).unwrap(),
+        )
+    }
+
+    #[test]
+    fn derive_x25519() {
+        let path = message_encryption_path(CAP26KeyKind::MessageEncryption);
+        let sut = BIP39Seed::sample()
+            .derive_x25519_private_key(path.clone())
+            .unwrap();
+        assert_eq!(
+            sut,
+            BIP39Seed::sample()
+                .derive_x25519_private_key(path.clone())
+                .unwrap()
+        );
+        assert_eq!(sut.clone(), sut);
+        let ed25519 = BIP39Seed::sample()
+            ._derive_ed25519_private_key(&path.into())
+            .unwrap();
+        assert_ne!(sut.public_key().to_bytes(), ed25519.public_key().to_bytes());
+        assert_eq!(format!("{:?}", sut), "X25519PrivateKey(<redacted>)");
+    }
+
+    #[test]
+    fn derive_ed25519_message_encryption_is_err() {
+        for path in [
+            "m/44H/1022H/1H/525H/1391H/0H",
+            "m/44H/1022H/1H/618H/1391H/0H",
+            "m/44H/1022H/1H/525H/1391H/0S",
+        ] {
+            assert_eq!(
+                BIP39Seed::sample()
+                    .derive_ed25519_private_key(HDPath::from_str(path).unwrap())
+                    .err(),
+                Some(CommonError::MessageEncryptionKeyKindRequiresX25519)
+            );
+        }
+    }
+
+    #[test]
+    fn derive_x25519_wrong_key_kind_is_err() {
+        let path = message_encryption_path(CAP26KeyKind::TransactionSigning);
+        assert_eq!(
+            BIP39Seed::sample().derive_x25519_private_key(path).err(),
+            Some(CommonError::WrongKeyKind {
+                expected: CAP26KeyKind::MessageEncryption,
+                found: CAP26KeyKind::TransactionSigning,
+            })
+        );
+    }
+
+    #[test]
+    fn derive_private_key_message_encryption_is_err() {
+        let path =
+            DerivationPath::account(message_encryption_path(CAP26KeyKind::MessageEncryption));
+        assert_eq!(
+            BIP39Seed::sample().derive_private_key(&path).err(),
+            Some(CommonError::MessageEncryptionKeyKindRequiresX25519)
+        );
+    }
+
+    #[test]
+    fn x25519_diffie_hellman_is_symmetric() {
+        let alice = X25519PrivateKey::from_bytes(&[1; 32]);
+        let bob = X25519PrivateKey::from_bytes(&[2; 32]);
+        let shared = alice.diffie_hellman(&bob.public_key()).unwrap();
+        assert_eq!(shared, bob.diffie_hellman(&alice.public_key()).unwrap());
+        assert_eq!(format!("{:?}", shared), "X25519SharedSecret(<redacted>)");
+    }
+
+    #[test]
+    fn x25519_diffie_hellman_low_order_is_err() {
+        let alice = X25519PrivateKey::from_bytes(&[1; 32]);
+        assert_eq!(
+            alice
+                .diffie_hellman(&X25519PublicKey::from_bytes([0; 32]))
+                .err(),
+            Some(CommonError::NonContributoryX25519SharedSecret)
+        );
+    }
+
+    #[test]
+    fn derive_account_address() {
+        let path = CAP26AccountPath::from_str("m/44H/1022H/1H/525H/1460H/0H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample()
+                .derive_account_address(&path)
+                .unwrap()
+                .to_string(),
+            "account_rdx12939q7jvjc0q9vvqtc87uv6eu334yagtak7k9udekafy66gpvu222n"
+        );
+        let path = CAP26AccountPath::from_str("m/44H/1022H/2H/525H/1460H/0H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample()
+                .derive_account_address(&path)
+                .unwrap()
+                .to_string(),
+            "account_tdx_2_129y4vzvvdwjv84hfvj38ss226avmknqjydhu9xuxjuadtwhfst7yc5"
+        );
+    }
+
+    #[test]
+    fn derive_identity_address() {
+        let path = CAP26IdentityPath::from_str("m/44H/1022H/1H/618H/1460H/0H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample()
+                .derive_identity_address(&path)
+                .unwrap()
+                .to_string(),
+            "identity_rdx12g5v7qspjdxwfhvpa2lzjmxgtr2ywgeuec5eftaxn924d7qxcfw8l8"
+        );
+        let path = CAP26IdentityPath::from_str("m/44H/1022H/2H/618H/1460H/1H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample()
+                .derive_identity_address(&path)
+                .unwrap()
+                .to_string(),
+            "identity_tdx_2_12fvk5f3fjzp33h82uppeuqwtcqmxp4zx4ts0e3nw56v55nnw69xenv"
+        );
+    }
+
+    #[test]
+    fn derive_account_address_wrong_key_kind_is_err() {
+        let path = CAP26AccountPath::from_str("m/44H/1022H/1H/525H/1678H/0H").unwrap();
+        assert_eq!(
+            BIP39Seed::sample().derive_account_address(&path),
+            Err(CommonError::WrongKeyKind {
+                expected: CAP26KeyKind::TransactionSigning,
+                found: CAP26KeyKind::AuthenticationSigning,
+            })
+        );
+    }
+
+    #[test]
+    fn seed_zeroize() {
+        let mut sut = BIP39Seed::sample();
+        assert_ne!(sut.0, [0; 64]);
+        sut.zeroize();
+        assert_eq!(sut.0, [0; 64]);
+    }
+
+    #[test]
+    fn seed_eq() {
+        assert_eq!(BIP39Seed::sample(), BIP39Seed::sample());
+        assert_ne!(BIP39Seed::sample(), BIP39Seed::from_bytes([0; 64]));
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let ed25519 = BIP39Seed::sample()
+            .derive_ed25519_private_key(CAP26GetIDPath)
+            .unwrap();
+        let secp256k1 = BIP39Seed::sample()
+            .derive_secp256k1_private_key(CAP26GetIDPath)
+            .unwrap();
+        assert_eq!(
+            format!("{:?}", BIP39Seed::sample()),
+            "BIP39Seed(<redacted>)"
+        );
+        assert_eq!(format!("{:?}", ed25519), "Ed25519PrivateKey(<redacted>)");
+        assert_eq!(
+            format!("{:?}", secp256k1),
+            "Secp256k1PrivateKey(<redacted>)"
+        );
+    }
+
+    #[test]
+    fn private_key_eq() {
+        let ed25519 = |path: &str| {
+            BIP39Seed::sample()
+                .derive_ed25519_private_key(HDPath::from_str(path).unwrap())
+                .unwrap()
+        };
+        assert_eq!(ed25519("m/0H"), ed25519("m/0H"));
+        assert_ne!(ed25519("m/0H"), ed25519("m/1H"));
+        let secp256k1 = |path: &str| {
+            BIP39Seed::sample()
+                .derive_secp256k1_private_key(HDPath::from_str(path).unwrap())
+                .unwrap()
+        };
+        assert_eq!(secp256k1("m/0"), secp256k1("m/0"));
+        assert_ne!(secp256k1("m/0"), secp256k1("m/1"));
     }
 }
 
@@ -226,7 +907,7 @@ mod tests_secp256k1 {
         let seed = mnemonic.to_bip39_seed(passphrase.as_ref());
         let path = P::from_str(path.as_ref()).unwrap();
         let path: HDPath = path.into();
-        let private_key = seed.derive_secp256k1_private_key(path);
+        let private_key = seed.derive_secp256k1_private_key(path).unwrap();
         assert(&private_key, &private_key.public_key());
     }
 
@@ -261,6 +942,103 @@ mod tests_secp256k1 {
         test_assert_key_hexes::<BIP44LikePath>("pledge rely stick hard snow ice sign source sample pledge rely sample pledge rely sample pledge rely sample pledge rely sample stick sample cactus", "m/44H/1022H/0H/0/5H", "09c5ec59b0cc08d07e5ed4aaee8c583264ffa060563d4b531e15db13d35b2a87", "038c9ae8b50356cfd87b6e8c069c14cbda692578e87cd41291701947a2d1b794c4");
     }
 
+    #[test]
+    fn public_root_is_err() {
+        let seed = BIP39Seed::sample();
+        let path = HDPath::from_str("M/0/1").unwrap();
+        assert_eq!(
+            seed.derive_secp256k1_private_key(path).err(),
+            Some(CommonError::PrivateKeyDerivationFromPublicRoot)
+        );
+    }
+
+    fn private_key_one() -> Secp256k1PrivateKey {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        Secp256k1PrivateKey::from_bytes(&bytes).unwrap()
+    }
+
+    /// Widely used RFC6979 vector, private key `1`.
+    #[test]
+    fn sign_rfc6979() {
+        let signature = private_key_one().sign("Satoshi Nakamoto");
+        assert_eq!(
+            hex::encode(&signature.to_bytes()[..64]),
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d82442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        );
+    }
+
+    #[test]
+    fn sign_is_deterministic() {
+        let sut = private_key_one();
+        assert_eq!(sut.sign("Hello"), sut.sign("Hello"));
+        assert_ne!(sut.sign("Hello"), sut.sign("Hello!"));
+    }
+
+    #[test]
+    fn verify() {
+        let sut = private_key_one();
+        let signature = sut.sign("Hello");
+        assert!(sut.public_key().verify(&signature, "Hello"));
+        assert!(!sut.public_key().verify(&signature, "Hello!"));
+    }
+
+    #[test]
+    fn recover_public_key() {
+        let sut = BIP39Seed::sample()
+            .derive_secp256k1_private_key(BIP44LikePath::sample())
+            .unwrap();
+        let signature = sut.sign("Hello");
+        assert_eq!(
+            signature.recover_public_key("Hello").unwrap(),
+            sut.public_key()
+        );
+        assert_ne!(
+            signature.recover_public_key("Hello!").unwrap(),
+            sut.public_key()
+        );
+    }
+
+    #[test]
+    fn signature_json_roundtrip() {
+        let sut = private_key_one().sign("Hello");
+        assert_json_value_eq_after_roundtrip(&sut, serde_json::json!(sut.to_hex()));
+        assert_eq!(Secp256k1Signature::from_str(&sut.to_hex()).unwrap(), sut);
+        assert_eq!(format!("{:?}", sut), sut.to_hex());
+    }
+
+    #[test]
+    fn signature_from_str_invalid() {
+        assert_eq!(
+            Secp256k1Signature::from_str("deadbeef"),
+            Err(CommonError::InvalidSecp256k1Signature)
+        );
+        let mut bytes = private_key_one().sign("Hello").to_bytes();
+        bytes[64] = 9;
+        assert_eq!(
+            Secp256k1Signature::from_bytes(&bytes),
+            Err(CommonError::InvalidSecp256k1Signature)
+        );
+    }
+
+    #[test]
+    fn derive_legacy_olympia_account_address() {
+        let seed = BIP39Seed::sample();
+        let address = |path: &str| {
+            seed.derive_legacy_olympia_account_address(&BIP44LikePath::from_str(path).unwrap())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            address("m/44H/1022H/0H/0/0"),
+            "rdx1qspmctkg7dngep54w7lkdda537x7u4acxwgk4fcfvmay55pfkcamrrc0lcarp"
+        );
+        assert_eq!(
+            address("m/44H/1022H/0H/0/1"),
+            "rdx1qspu3f49wy944waqjdquysuzmcez9yf3yr0w2zzw3p6jn0uzruuh8cs4tyy8l"
+        );
+    }
+
     #[test]
     fn bip44_strict_bip44_equip() {
         test_assert_key_hexes::<BIP44LikePath>(
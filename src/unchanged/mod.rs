@@ -1,13 +1,17 @@
+#[cfg(feature = "std")]
 mod assert_eq_json;
 mod common_error;
+#[cfg(feature = "std")]
 mod derivation;
 mod entity_kind;
 mod has_sample_values;
 mod key_kind;
 mod network_id;
 
+#[cfg(feature = "std")]
 pub use assert_eq_json::*;
 pub use common_error::*;
+#[cfg(feature = "std")]
 pub use derivation::*;
 pub use entity_kind::*;
 pub use has_sample_values::*;
@@ -83,6 +83,32 @@ impl NetworkID {
     pub fn discriminant(&self) -> u8 {
         *self as u8
     }
+
+    /// The suffix of the Bech32m HRP of addresses on this network, e.g.
+    /// `rdx` in `account_rdx1...` on Mainnet and `tdx_2_` in
+    /// `account_tdx_2_1...` on Stokenet.
+    pub fn hrp_suffix(&self) -> String {
+        match self {
+            Self::Mainnet => "rdx".to_owned(),
+            Self::Simulator => "sim".to_owned(),
+            _ => format!("tdx_{:x}_", self.discriminant()),
+        }
+    }
+
+    /// The network with the HRP suffix `suffix`, see `hrp_suffix`.
+    pub fn from_hrp_suffix(suffix: &str) -> Result<Self> {
+        match suffix {
+            "rdx" => Ok(Self::Mainnet),
+            "sim" => Ok(Self::Simulator),
+            _ => suffix
+                .strip_prefix("tdx_")
+                .and_then(|s| s.strip_suffix('_'))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .and_then(Self::from_repr)
+                .filter(|network_id| network_id.hrp_suffix() == suffix)
+                .ok_or(CommonError::InvalidNetworkID),
+        }
+    }
 }
 
 impl TryFrom<U31> for NetworkID {
@@ -91,3 +117,49 @@ impl TryFrom<U31> for NetworkID {
         Self::from_repr(u32::from(value) as u8).ok_or(CommonError::InvalidNetworkID)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = NetworkID;
+
+    #[test]
+    fn hrp_suffix() {
+        assert_eq!(Sut::Mainnet.hrp_suffix(), "rdx");
+        assert_eq!(Sut::Stokenet.hrp_suffix(), "tdx_2_");
+        assert_eq!(Sut::Kisharnet.hrp_suffix(), "tdx_c_");
+        assert_eq!(Sut::Enkinet.hrp_suffix(), "tdx_21_");
+        assert_eq!(Sut::Simulator.hrp_suffix(), "sim");
+    }
+
+    #[test]
+    fn from_hrp_suffix_roundtrip() {
+        for sut in [
+            Sut::Mainnet,
+            Sut::Stokenet,
+            Sut::Adapanet,
+            Sut::Nebunet,
+            Sut::Kisharnet,
+            Sut::Ansharnet,
+            Sut::Zabanet,
+            Sut::Enkinet,
+            Sut::Hammunet,
+            Sut::Nergalnet,
+            Sut::Mardunet,
+            Sut::Simulator,
+        ] {
+            assert_eq!(Sut::from_hrp_suffix(&sut.hrp_suffix()), Ok(sut));
+        }
+    }
+
+    #[test]
+    fn from_hrp_suffix_invalid() {
+        for suffix in ["", "tdx_1_", "tdx_02_", "tdx_99_", "tdx_2", "xrd"] {
+            assert_eq!(
+                Sut::from_hrp_suffix(suffix),
+                Err(CommonError::InvalidNetworkID)
+            );
+        }
+    }
+}